@@ -33,14 +33,22 @@ export const governanceParametersAtom = runtime.atom(
   })
 )
 
+// Byte limits the blueprint applies to temperature check drafts
+export const draftLimitsAtom = runtime.atom(
+  Effect.gen(function* () {
+    const governanceComponent = yield* GovernanceComponent
+    return yield* governanceComponent.getDraftLimits()
+  })
+)
+
 export const updateGovernanceParametersAtom = runtime.fn(
   Effect.fn(
     function* (
       input: {
-        temperatureCheckDays: number
+        temperatureCheckLengthMinutes: number
         temperatureCheckQuorum: string
         temperatureCheckApprovalThreshold: string
-        proposalLengthDays: number
+        proposalLengthMinutes: number
         proposalQuorum: string
         proposalApprovalThreshold: string
       },
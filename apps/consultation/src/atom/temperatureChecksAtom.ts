@@ -13,6 +13,7 @@ import {
 } from 'effect'
 import { GatewayApiClientLayer } from 'shared/gateway'
 import {
+  DraftLimitExceededError,
   GovernanceComponent,
   type TemperatureCheckId,
   GovernanceConfigLayer
@@ -135,7 +136,10 @@ export const makeTemperatureCheckAtom = runtime.fn(
           if (cause.error instanceof WalletErrorResponse) {
             return Option.some(cause.error.message ?? 'Wallet error')
           }
-          if (cause.error instanceof NoAccountConnectedError) {
+          if (
+            cause.error instanceof NoAccountConnectedError ||
+            cause.error instanceof DraftLimitExceededError
+          ) {
             return Option.some(cause.error.message)
           }
         }
@@ -63,6 +63,17 @@ export function formatXrd(value: number): string {
   return value.toFixed(2)
 }
 
+export function formatMinutes(minutes: number): string {
+  const unit =
+    minutes % 1440 === 0
+      ? { size: 1440, name: 'day' }
+      : minutes % 60 === 0
+        ? { size: 60, name: 'hour' }
+        : { size: 1, name: 'minute' }
+  const count = minutes / unit.size
+  return `${count} ${unit.name}${count === 1 ? '' : 's'}`
+}
+
 export function formatDateTime(date: Date): string {
   return date.toLocaleString('en-GB', {
     day: 'numeric',
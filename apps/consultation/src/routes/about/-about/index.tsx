@@ -6,7 +6,7 @@ import { isAdminAtom } from '@/atom/adminAtom'
 import { useCurrentAccount } from '@/hooks/useCurrentAccount'
 import { Button } from '@/components/ui/button'
 import { H1 } from '@/components/ui/typography'
-import { formatXrd } from '@/lib/utils'
+import { formatMinutes, formatXrd } from '@/lib/utils'
 
 export const Page = () => {
   const parametersResult = useAtomValue(governanceParametersAtom)
@@ -44,10 +44,10 @@ export const Page = () => {
         ))
         .onSuccess((parameters) => (
           <GovernanceContent
-            tcDays={parameters.temperature_check_days}
+            tcMinutes={parameters.temperature_check_length_minutes}
             tcQuorum={parameters.temperature_check_quorum}
             tcApproval={parameters.temperature_check_approval_threshold}
-            gpDays={parameters.proposal_length_days}
+            gpMinutes={parameters.proposal_length_minutes}
             gpQuorum={parameters.proposal_quorum}
             gpApproval={parameters.proposal_approval_threshold}
           />
@@ -58,17 +58,17 @@ export const Page = () => {
 }
 
 const GovernanceContent = ({
-  tcDays,
+  tcMinutes,
   tcQuorum,
   tcApproval,
-  gpDays,
+  gpMinutes,
   gpQuorum,
   gpApproval
 }: {
-  tcDays: number
+  tcMinutes: number
   tcQuorum: string
   tcApproval: string
-  gpDays: number
+  gpMinutes: number
   gpQuorum: string
   gpApproval: string
 }) => (
@@ -89,7 +89,7 @@ const GovernanceContent = ({
             vote to gauge community sentiment on a specific idea or direction.
           </p>
           <ul className="list-disc list-inside text-sm text-neutral-500 space-y-2 pl-2">
-            <li>Voting period: {tcDays} days</li>
+            <li>Voting period: {formatMinutes(tcMinutes)}</li>
             <li>Requires {formatXrd(Number(tcQuorum))} XRD quorum</li>
             <li>Must pass with &gt;{(Number(tcApproval) * 100).toFixed(0)}% approval</li>
             <li>Successful TCs may be promoted to GPs by the Council</li>
@@ -106,7 +106,7 @@ const GovernanceContent = ({
             the community to choose from.
           </p>
           <ul className="list-disc list-inside text-sm text-neutral-500 space-y-2 pl-2">
-            <li>Voting period: {gpDays} days</li>
+            <li>Voting period: {formatMinutes(gpMinutes)}</li>
             <li>Requires {formatXrd(Number(gpQuorum))} XRD quorum</li>
             <li>Must pass with &gt;{(Number(gpApproval) * 100).toFixed(0)}% approval</li>
             <li>Multiple options available</li>
@@ -104,10 +104,10 @@ const AdminForm = () => {
     ))
     .onSuccess((parameters) => (
       <AdminFormWithValues
-        tcDays={parameters.temperature_check_days}
+        tcMinutes={parameters.temperature_check_length_minutes}
         tcQuorum={parameters.temperature_check_quorum}
         tcApproval={parameters.temperature_check_approval_threshold}
-        gpDays={parameters.proposal_length_days}
+        gpMinutes={parameters.proposal_length_minutes}
         gpQuorum={parameters.proposal_quorum}
         gpApproval={parameters.proposal_approval_threshold}
       />
@@ -116,17 +116,17 @@ const AdminForm = () => {
 }
 
 const AdminFormWithValues = ({
-  tcDays,
+  tcMinutes,
   tcQuorum,
   tcApproval,
-  gpDays,
+  gpMinutes,
   gpQuorum,
   gpApproval
 }: {
-  tcDays: number
+  tcMinutes: number
   tcQuorum: string
   tcApproval: string
-  gpDays: number
+  gpMinutes: number
   gpQuorum: string
   gpApproval: string
 }) => {
@@ -136,10 +136,10 @@ const AdminFormWithValues = ({
   const isSubmitting = updateResult.waiting
 
   const [form, setForm] = useState({
-    temperatureCheckDays: tcDays.toString(),
+    temperatureCheckLengthMinutes: tcMinutes.toString(),
     temperatureCheckQuorum: tcQuorum,
     temperatureCheckApprovalThreshold: tcApproval,
-    proposalLengthDays: gpDays.toString(),
+    proposalLengthMinutes: gpMinutes.toString(),
     proposalQuorum: gpQuorum,
     proposalApprovalThreshold: gpApproval
   })
@@ -151,10 +151,10 @@ const AdminFormWithValues = ({
   const handleSubmit = (e: React.FormEvent) => {
     e.preventDefault()
     updateParameters({
-      temperatureCheckDays: Number(form.temperatureCheckDays),
+      temperatureCheckLengthMinutes: Number(form.temperatureCheckLengthMinutes),
       temperatureCheckQuorum: form.temperatureCheckQuorum,
       temperatureCheckApprovalThreshold: form.temperatureCheckApprovalThreshold,
-      proposalLengthDays: Number(form.proposalLengthDays),
+      proposalLengthMinutes: Number(form.proposalLengthMinutes),
       proposalQuorum: form.proposalQuorum,
       proposalApprovalThreshold: form.proposalApprovalThreshold
     })
@@ -182,14 +182,14 @@ const AdminFormWithValues = ({
             </CardHeader>
             <CardContent className="space-y-4">
               <div className="space-y-2">
-                <Label htmlFor="tc-days">Voting Period (days)</Label>
+                <Label htmlFor="tc-minutes">Voting Period (minutes)</Label>
                 <Input
-                  id="tc-days"
+                  id="tc-minutes"
                   type="number"
                   min="1"
-                  value={form.temperatureCheckDays}
+                  value={form.temperatureCheckLengthMinutes}
                   onChange={(e) =>
-                    handleChange('temperatureCheckDays', e.target.value)
+                    handleChange('temperatureCheckLengthMinutes', e.target.value)
                   }
                 />
               </div>
@@ -232,14 +232,14 @@ const AdminFormWithValues = ({
             </CardHeader>
             <CardContent className="space-y-4">
               <div className="space-y-2">
-                <Label htmlFor="gp-days">Voting Period (days)</Label>
+                <Label htmlFor="gp-minutes">Voting Period (minutes)</Label>
                 <Input
-                  id="gp-days"
+                  id="gp-minutes"
                   type="number"
                   min="1"
-                  value={form.proposalLengthDays}
+                  value={form.proposalLengthMinutes}
                   onChange={(e) =>
-                    handleChange('proposalLengthDays', e.target.value)
+                    handleChange('proposalLengthMinutes', e.target.value)
                   }
                 />
               </div>
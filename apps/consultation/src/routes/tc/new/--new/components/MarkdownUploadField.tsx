@@ -1,5 +1,5 @@
 import { FileTextIcon, UploadIcon, XIcon } from 'lucide-react'
-import { useCallback, useId, useRef, useState } from 'react'
+import { useCallback, useId, useMemo, useRef, useState } from 'react'
 import { Button } from '@/components/ui/button'
 import {
   Field,
@@ -9,11 +9,20 @@ import {
 } from '@/components/ui/field'
 import { withForm } from '../formHook'
 import { temperatureCheckFormOpts } from '../formOptions'
-import { DescriptionSchema, effectSchemaValidator } from '../schema'
+import {
+  type DraftLimits,
+  effectSchemaValidator,
+  makeDescriptionSchema
+} from '../schema'
 
 export const MarkdownUploadField = withForm({
   ...temperatureCheckFormOpts,
-  render: function Render({ form }) {
+  props: {} as { limits: DraftLimits },
+  render: function Render({ form, limits }) {
+    const descriptionSchema = useMemo(
+      () => makeDescriptionSchema(limits),
+      [limits]
+    )
     const [uploadedFile, setUploadedFile] = useState<{
       name: string
       size: number
@@ -61,8 +70,8 @@ export const MarkdownUploadField = withForm({
       <form.Field
         name="description"
         validators={{
-          onBlur: effectSchemaValidator(DescriptionSchema),
-          onChange: effectSchemaValidator(DescriptionSchema)
+          onBlur: effectSchemaValidator(descriptionSchema),
+          onChange: effectSchemaValidator(descriptionSchema)
         }}
       >
         {(field) => {
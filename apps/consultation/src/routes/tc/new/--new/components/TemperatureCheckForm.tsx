@@ -1,8 +1,9 @@
 import { Result, useAtom, useAtomValue } from '@effect-atom/atom-react'
 import { useStore } from '@tanstack/react-form'
 import { LoaderIcon } from 'lucide-react'
-import { useEffect, useId, useRef } from 'react'
+import { useEffect, useId, useMemo, useRef } from 'react'
 import { accountsAtom } from '@/atom/dappToolkitAtom'
+import { draftLimitsAtom } from '@/atom/governanceParametersAtom'
 import { makeTemperatureCheckAtom } from '@/atom/temperatureChecksAtom'
 import { Button } from '@/components/ui/button'
 import {
@@ -25,11 +26,12 @@ import { Textarea } from '@/components/ui/textarea'
 import { useAppForm } from '../formHook'
 import { temperatureCheckFormOpts } from '../formOptions'
 import {
+  type DraftLimits,
   effectSchemaValidator,
-  RadixTalkUrlSchema,
-  ShortDescriptionSchema,
-  TemperatureCheckFormSchema,
-  TitleSchema
+  makeShortDescriptionSchema,
+  makeTemperatureCheckFormSchema,
+  makeTitleSchema,
+  RadixTalkUrlSchema
 } from '../schema'
 import { LinksField } from './LinksField'
 import { MarkdownUploadField } from './MarkdownUploadField'
@@ -41,20 +43,52 @@ type TemperatureCheckFormProps = {
   onSuccess?: (result: unknown) => void
 }
 
-export function TemperatureCheckForm({
+export function TemperatureCheckForm(props: TemperatureCheckFormProps) {
+  const draftLimitsResult = useAtomValue(draftLimitsAtom)
+
+  // The on-ledger draft limits drive validation, so wait for them before
+  // rendering the form
+  return Result.builder(draftLimitsResult)
+    .onInitial(() => (
+      <div className="flex justify-center py-12">
+        <LoaderIcon className="size-6 animate-spin text-muted-foreground" />
+      </div>
+    ))
+    .onFailure(() => (
+      <p className="text-sm text-destructive text-center">
+        Failed to load draft limits. Please try again.
+      </p>
+    ))
+    .onSuccess((limits) => (
+      <TemperatureCheckFormContent {...props} limits={limits} />
+    ))
+    .render()
+}
+
+function TemperatureCheckFormContent({
   maxVoteOptions = 10,
-  onSuccess
-}: TemperatureCheckFormProps) {
+  onSuccess,
+  limits
+}: TemperatureCheckFormProps & { limits: DraftLimits }) {
   const [makeResult, makeTemperatureCheck] = useAtom(makeTemperatureCheckAtom)
   const accountsResult = useAtomValue(accountsAtom)
   const formId = useId()
   const titleId = `${formId}-title`
   const shortDescriptionId = `${formId}-shortDescription`
 
+  const schemas = useMemo(
+    () => ({
+      form: makeTemperatureCheckFormSchema(limits),
+      title: makeTitleSchema(limits),
+      shortDescription: makeShortDescriptionSchema(limits)
+    }),
+    [limits]
+  )
+
   const form = useAppForm({
     ...temperatureCheckFormOpts,
     validators: {
-      onSubmit: effectSchemaValidator(TemperatureCheckFormSchema)
+      onSubmit: effectSchemaValidator(schemas.form)
     },
     onSubmit: ({ value }) => {
       // Combine radixTalkUrl with additional links
@@ -140,8 +174,8 @@ export function TemperatureCheckForm({
             <form.Field
               name="title"
               validators={{
-                onBlur: effectSchemaValidator(TitleSchema),
-                onChange: effectSchemaValidator(TitleSchema)
+                onBlur: effectSchemaValidator(schemas.title),
+                onChange: effectSchemaValidator(schemas.title)
               }}
             >
               {(field) => {
@@ -171,8 +205,8 @@ export function TemperatureCheckForm({
             <form.Field
               name="shortDescription"
               validators={{
-                onBlur: effectSchemaValidator(ShortDescriptionSchema),
-                onChange: effectSchemaValidator(ShortDescriptionSchema)
+                onBlur: effectSchemaValidator(schemas.shortDescription),
+                onChange: effectSchemaValidator(schemas.shortDescription)
               }}
             >
               {(field) => {
@@ -205,7 +239,7 @@ export function TemperatureCheckForm({
             </form.Field>
 
             {/* Description (Markdown File Upload) */}
-            <MarkdownUploadField form={form} />
+            <MarkdownUploadField form={form} limits={limits} />
 
             {/* RadixTalk URL */}
             <form.Field
@@ -265,7 +299,11 @@ export function TemperatureCheckForm({
           </CardDescription>
 
           {/* Vote Options */}
-          <VoteOptionsField form={form} maxOptions={maxVoteOptions} />
+          <VoteOptionsField
+            form={form}
+            maxOptions={maxVoteOptions}
+            limits={limits}
+          />
         </CardContent>
       </Card>
 
@@ -1,4 +1,5 @@
 import { PlusIcon, Trash2Icon } from 'lucide-react'
+import { useMemo } from 'react'
 import { Button } from '@/components/ui/button'
 import {
   Field,
@@ -12,13 +13,20 @@ import {
   temperatureCheckFormOpts,
   type VoteOption
 } from '../formOptions'
+import {
+  type DraftLimits,
+  effectSchemaValidator,
+  makeOptionLabelSchema
+} from '../schema'
 
 export const VoteOptionsField = withForm({
   ...temperatureCheckFormOpts,
   props: {
     maxOptions: 10
-  },
-  render: function Render({ form, maxOptions }) {
+  } as { maxOptions: number; limits: DraftLimits },
+  render: function Render({ form, maxOptions, limits }) {
+    const labelSchema = useMemo(() => makeOptionLabelSchema(limits), [limits])
+
     return (
       <form.Field
         name="voteOptions"
@@ -51,11 +59,8 @@ export const VoteOptionsField = withForm({
                     <form.Field
                       name={`voteOptions[${index}].label`}
                       validators={{
-                        onBlur: ({ value }) =>
-                          !value
-                            ? { message: 'Label is required' }
-                            : undefined,
-                        onChange: () => undefined
+                        onBlur: effectSchemaValidator(labelSchema),
+                        onChange: effectSchemaValidator(labelSchema)
                       }}
                     >
                       {(subField) => {
@@ -1,4 +1,5 @@
 import { ParseResult, Schema } from 'effect'
+import { utf8ByteLength } from 'shared/governance/schemas'
 
 export function effectSchemaValidator<T, I>(schema: Schema.Schema<T, I>) {
   return ({ value }: { value: unknown }) => {
@@ -11,23 +12,42 @@ export function effectSchemaValidator<T, I>(schema: Schema.Schema<T, I>) {
   }
 }
 
-export const TitleSchema = Schema.String.pipe(
-  Schema.minLength(1, { message: () => 'Title is required' }),
-  Schema.maxLength(200, {
-    message: () => 'Title must be 200 characters or less'
-  })
-)
+/** On-ledger byte limits of a draft, read from the governance parameters */
+export type DraftLimits = {
+  max_title_bytes: number
+  max_short_description_bytes: number
+  max_inline_description_bytes: number
+  max_option_label_bytes: number
+}
 
-export const ShortDescriptionSchema = Schema.String.pipe(
-  Schema.minLength(1, { message: () => 'Short description is required' }),
-  Schema.maxLength(500, {
-    message: () => 'Short description must be 500 characters or less'
+const maxBytes = (max: number, field: string) =>
+  Schema.filter((value: string) => utf8ByteLength(value) <= max, {
+    message: () => `${field} must be ${max} bytes or less`
   })
-)
 
-export const DescriptionSchema = Schema.String.pipe(
-  Schema.minLength(1, { message: () => 'Description is required' })
-)
+export const makeTitleSchema = (limits: DraftLimits) =>
+  Schema.String.pipe(
+    Schema.minLength(1, { message: () => 'Title is required' }),
+    maxBytes(limits.max_title_bytes, 'Title')
+  )
+
+export const makeShortDescriptionSchema = (limits: DraftLimits) =>
+  Schema.String.pipe(
+    Schema.minLength(1, { message: () => 'Short description is required' }),
+    maxBytes(limits.max_short_description_bytes, 'Short description')
+  )
+
+export const makeDescriptionSchema = (limits: DraftLimits) =>
+  Schema.String.pipe(
+    Schema.minLength(1, { message: () => 'Description is required' }),
+    maxBytes(limits.max_inline_description_bytes, 'Description')
+  )
+
+export const makeOptionLabelSchema = (limits: DraftLimits) =>
+  Schema.String.pipe(
+    Schema.minLength(1, { message: () => 'Label is required' }),
+    maxBytes(limits.max_option_label_bytes, 'Label')
+  )
 
 export const RadixTalkUrlSchema = Schema.URL.pipe(
   Schema.filter((url) => url.origin === 'https://radixtalk.com', {
@@ -50,26 +70,27 @@ export const LinkSchema = Schema.String.pipe(
   )
 )
 
-const VoteOptionSchema = Schema.Struct({
-  id: Schema.String,
-  label: Schema.String.pipe(
-    Schema.minLength(1, { message: () => 'Option label is required' })
-  )
-})
-
-export const TemperatureCheckFormSchema = Schema.Struct({
-  title: TitleSchema,
-  shortDescription: ShortDescriptionSchema,
-  description: DescriptionSchema,
-  radixTalkUrl: RadixTalkUrlSchema,
-  links: Schema.Array(LinkSchema),
-  voteOptions: Schema.Array(VoteOptionSchema).pipe(
-    Schema.minItems(2, { message: () => 'At least 2 options required' })
-  ),
-  maxSelections: Schema.Union(
-    Schema.Literal(1),
-    Schema.Number.pipe(Schema.greaterThan(1))
-  )
-})
+export const makeTemperatureCheckFormSchema = (limits: DraftLimits) =>
+  Schema.Struct({
+    title: makeTitleSchema(limits),
+    shortDescription: makeShortDescriptionSchema(limits),
+    description: makeDescriptionSchema(limits),
+    radixTalkUrl: RadixTalkUrlSchema,
+    links: Schema.Array(LinkSchema),
+    voteOptions: Schema.Array(
+      Schema.Struct({
+        id: Schema.String,
+        label: makeOptionLabelSchema(limits)
+      })
+    ).pipe(
+      Schema.minItems(2, { message: () => 'At least 2 options required' })
+    ),
+    maxSelections: Schema.Union(
+      Schema.Literal(1),
+      Schema.Number.pipe(Schema.greaterThan(1))
+    )
+  })
 
-export type TemperatureCheckFormData = typeof TemperatureCheckFormSchema.Type
+export type TemperatureCheckFormData = Schema.Schema.Type<
+  ReturnType<typeof makeTemperatureCheckFormSchema>
+>
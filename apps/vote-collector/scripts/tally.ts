@@ -118,12 +118,15 @@ const tallyTemperatureCheck = (id: number) =>
 
     yield* Effect.log('Fetching votes from chain...')
 
-    const dedupedVotes = yield* fetchDedupedTemperatureCheckVotes(governance, {
+    const allDedupedVotes = yield* fetchDedupedTemperatureCheckVotes(governance, {
       keyValueStoreAddress: tc.votes,
       fromIndexInclusive: 0,
       toIndexInclusive: tc.voteCount
     })
 
+    // Accounts whose latest record is a retraction no longer count as voters
+    const dedupedVotes = allDedupedVotes.filter((v) => v.votes.length > 0)
+
     yield* Effect.log(`Unique voters: ${dedupedVotes.length}`)
     yield* Effect.log('Snapshotting vote power at TC start date...')
 
@@ -177,12 +180,15 @@ const tallyProposal = (id: number) =>
 
     yield* Effect.log('Fetching votes from chain...')
 
-    const dedupedVotes = yield* fetchDedupedProposalVotes(governance, {
+    const allDedupedVotes = yield* fetchDedupedProposalVotes(governance, {
       keyValueStoreAddress: proposal.votes,
       fromIndexInclusive: 0,
       toIndexInclusive: proposal.voteCount
     })
 
+    // Accounts whose latest record is a retraction no longer count as voters
+    const dedupedVotes = allDedupedVotes.filter((v) => v.votes.length > 0)
+
     yield* Effect.log(`Unique voters: ${dedupedVotes.length}`)
     yield* Effect.log('Snapshotting vote power at proposal start date...')
 
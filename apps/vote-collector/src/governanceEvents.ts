@@ -27,7 +27,7 @@ export class GovernanceEventProcessor extends Effect.Service<GovernanceEventProc
 
           const id = TemperatureCheckId.make(Number(idField.value))
 
-          yield* Effect.log('TemperatureCheckVotedEvent detected', { id })
+          yield* Effect.log(`${event.identifier.event} detected`, { id })
 
           const tc = yield* governance.getTemperatureCheckById(id)
 
@@ -50,7 +50,7 @@ export class GovernanceEventProcessor extends Effect.Service<GovernanceEventProc
 
           const id = ProposalId.make(Number(idField.value))
 
-          yield* Effect.log('ProposalVotedEvent detected', { id })
+          yield* Effect.log(`${event.identifier.event} detected`, { id })
 
           const proposal = yield* governance.getProposalById(id)
 
@@ -63,9 +63,13 @@ export class GovernanceEventProcessor extends Effect.Service<GovernanceEventProc
           })
         }).pipe(Effect.orDie)
 
+      // Retractions append a superseding record to the votes KVS, so they
+      // trigger the same recalculation as a vote
       const handlerMap = new Map([
         ['TemperatureCheckVotedEvent', handleTemperatureCheckVoted],
-        ['ProposalVotedEvent', handleProposalVoted]
+        ['TemperatureCheckVoteRetractedEvent', handleTemperatureCheckVoted],
+        ['ProposalVotedEvent', handleProposalVoted],
+        ['ProposalVoteRetractedEvent', handleProposalVoted]
       ])
 
       const processBatch = (batch: CommittedTransactionInfo[]) =>
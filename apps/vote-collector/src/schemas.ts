@@ -8,13 +8,58 @@ export const VoteDelegation = s.struct({
   delegators: s.internalAddress()
 })
 
+export const AntiSnipingRule = s.struct({
+  window_minutes: s.number(),
+  extension_minutes: s.number(),
+  max_extension_minutes: s.number()
+})
+
+export const RevotePolicy = s.enum([
+  { variant: 'NoRevotes', schema: s.structNullable({}) },
+  { variant: 'Unlimited', schema: s.structNullable({}) },
+  { variant: 'AtMost', schema: s.tuple([s.number()]) },
+  {
+    variant: 'UntilLockWindow',
+    schema: s.struct({ lock_minutes: s.number() })
+  }
+])
+
+export const DraftLimits = s.struct({
+  max_title_bytes: s.number(),
+  max_short_description_bytes: s.number(),
+  max_inline_description_bytes: s.number(),
+  max_option_label_bytes: s.number(),
+  max_option_description_bytes: s.number()
+})
+
+export const PowerMode = s.enum([
+  { variant: 'Unweighted', schema: s.structNullable({}) },
+  { variant: 'MerkleSnapshot', schema: s.structNullable({}) },
+  { variant: 'LsuProofs', schema: s.structNullable({}) },
+  { variant: 'VoteEscrow', schema: s.structNullable({}) }
+])
+
 export const GovernanceParameters = s.struct({
-  temperature_check_days: s.number(),
+  temperature_check_length_minutes: s.number(),
   temperature_check_quorum: s.decimal(),
   temperature_check_approval_threshold: s.decimal(),
-  proposal_length_days: s.number(),
+  proposal_length_minutes: s.number(),
   proposal_quorum: s.decimal(),
-  proposal_approval_threshold: s.decimal()
+  proposal_approval_threshold: s.decimal(),
+  max_start_delay_minutes: s.number(),
+  max_deadline_extension_minutes: s.number(),
+  anti_sniping: s.enum([
+    { variant: 'None', schema: s.structNullable({}) },
+    { variant: 'Some', schema: s.tuple([AntiSnipingRule]) }
+  ]),
+  revote_policy: RevotePolicy,
+  max_rationale_length: s.number(),
+  draft_limits: DraftLimits,
+  abstain_option_label: s.enum([
+    { variant: 'None', schema: s.structNullable({}) },
+    { variant: 'Some', schema: s.tuple([s.string()]) }
+  ]),
+  power_mode: PowerMode
 })
 
 export const Governance = s.struct({
@@ -1,8 +1,9 @@
 import type { AccountAddress } from '@radix-effects/shared'
-import { Array as A, Effect, flow } from 'effect'
+import { Array as A, Effect, flow, Option } from 'effect'
 import type { GovernanceComponent } from 'shared/governance/index'
 import type { KeyValueStoreAddress } from 'shared/schemas'
 
+// An account whose latest record is a retraction has no votes
 export type DedupedVote = { accountAddress: AccountAddress; votes: string[] }
 
 type FetchParams = {
@@ -27,7 +28,10 @@ export const fetchDedupedTemperatureCheckVotes = (
         A.map(
           (v): DedupedVote => ({
             accountAddress: v.accountAddress,
-            votes: [v.vote]
+            votes: Option.match(v.vote, {
+              onNone: () => [],
+              onSome: (vote) => [vote]
+            })
           })
         )
       )
@@ -45,7 +49,10 @@ export const fetchDedupedProposalVotes = (
         A.map(
           (v): DedupedVote => ({
             accountAddress: v.accountAddress,
-            votes: v.options.map(String)
+            votes: Option.match(v.options, {
+              onNone: () => [],
+              onSome: (options) => options.map(String)
+            })
           })
         )
       )
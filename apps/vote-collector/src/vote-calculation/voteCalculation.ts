@@ -162,11 +162,16 @@ export class VoteCalculation extends Effect.Service<VoteCalculation>()(
             A.groupBy((v) => v.accountAddress)
           )
 
-          const [firstTimeVotes, revotingVotes] = A.partition(
+          const [newVoters, revotingVotes] = A.partition(
             dedupedVotes,
             (v) => v.accountAddress in existingByAccount
           )
 
+          // An account that voted and retracted within this batch has nothing to record
+          const firstTimeVotes = A.filter(newVoters, (v) =>
+            A.isNonEmptyArray(v.votes)
+          )
+
           yield* Effect.log('Vote split', {
             firstTime: firstTimeVotes.length,
             revotes: revotingVotes.length
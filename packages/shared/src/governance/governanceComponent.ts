@@ -13,8 +13,10 @@ import { Array as A, Data, Effect, Option, pipe, Schema } from 'effect'
 import { parseSbor } from '../helpers/parseSbor'
 import {
   Governance,
+  ItemContent,
   KeyValueStoreAddress,
   ProposalKeyValueStoreValue,
+  TemperatureCheckContentKeyValueStoreKey,
   TemperatureCheckKeyValueStoreKey,
   TemperatureCheckKeyValueStoreValue,
   TemperatureCheckVoteKeyValueStoreKey,
@@ -38,9 +40,13 @@ import {
   TemperatureCheckSchema,
   TemperatureCheckVoteRecord,
   TemperatureCheckVoteSchema,
-  TemperatureCheckVoteValueSchema
+  TemperatureCheckVoteValueSchema,
+  utf8ByteLength
 } from './schemas'
 
+// Default Gateway limit on the number of keys in one key-value store data request
+const KEY_VALUE_STORE_MAX_KEYS_PER_REQUEST = 100
+
 export class KeyValueStoreNotFoundError extends Data.TaggedError(
   'KeyValueStoreNotFoundError'
 )<{
@@ -63,6 +69,12 @@ class ProposalNotFoundError extends Data.TaggedError('ProposalNotFoundError')<{
   message: string
 }> {}
 
+export class DraftLimitExceededError extends Data.TaggedError(
+  'DraftLimitExceededError'
+)<{
+  message: string
+}> {}
+
 export class GovernanceComponent extends Effect.Service<GovernanceComponent>()(
   'GovernanceComponent',
   {
@@ -101,6 +113,71 @@ export class GovernanceComponent extends Effect.Service<GovernanceComponent>()(
             )
           )
 
+      // Item content lives in its own KVS, keyed by temperature check ID.
+      // Proposals share the content of the temperature check they were elevated from.
+      const getTemperatureCheckContents = (ids: ReadonlyArray<number>) =>
+        Effect.gen(function* () {
+          const keyValueStoreAddress = yield* getComponentState().pipe(
+            Effect.map((result) =>
+              KeyValueStoreAddress.make(result.temperature_check_contents)
+            )
+          )
+
+          // The Gateway caps the number of keys per request, so look them up in chunks
+          const entries = yield* Effect.all(
+            pipe(
+              ids,
+              A.chunksOf(KEY_VALUE_STORE_MAX_KEYS_PER_REQUEST),
+              A.map((chunk) =>
+                keyValueStoreDataService({
+                  key_value_store_address: keyValueStoreAddress,
+                  keys: chunk.map((id) => ({
+                    key_json: { kind: 'U64' as const, value: id.toString() }
+                  }))
+                }).pipe(
+                  Effect.map((result) =>
+                    pipe(
+                      result,
+                      A.head,
+                      Option.map((item) => item.entries),
+                      Option.getOrElse(() => [])
+                    )
+                  )
+                )
+              )
+            ),
+            { concurrency: 'unbounded' }
+          ).pipe(Effect.map(A.flatten))
+
+          const contents = yield* Effect.all(
+            entries.map((entry) =>
+              Effect.all(
+                [
+                  parseSbor(
+                    entry.key.programmatic_json,
+                    TemperatureCheckContentKeyValueStoreKey
+                  ),
+                  parseSbor(entry.value.programmatic_json, ItemContent)
+                ],
+                { concurrency: 2 }
+              )
+            ),
+            { concurrency: 'unbounded' }
+          )
+          const contentById = new Map(contents)
+
+          return (id: number) =>
+            pipe(
+              Option.fromNullable(contentById.get(id)),
+              Option.getOrThrowWith(
+                () =>
+                  new TemperatureCheckNotFoundError({
+                    message: `Content of temperature check ${id} not found`
+                  })
+              )
+            )
+        })
+
       const getTemperatureChecks = () =>
         getComponentState().pipe(
           Effect.flatMap((componentState) =>
@@ -108,10 +185,9 @@ export class GovernanceComponent extends Effect.Service<GovernanceComponent>()(
               address: componentState.temperature_checks
             })
           ),
-          Effect.map((result) =>
-            pipe(
-              result.entries,
-              A.map((entry) =>
+          Effect.flatMap((result) =>
+            Effect.all(
+              result.entries.map((entry) =>
                 Effect.all(
                   [
                     parseSbor(
@@ -124,18 +200,25 @@ export class GovernanceComponent extends Effect.Service<GovernanceComponent>()(
                     )
                   ],
                   { concurrency: 2 }
-                ).pipe(
-                  Effect.flatMap(([key, value]) =>
+                )
+              )
+            )
+          ),
+          Effect.flatMap((headers) =>
+            getTemperatureCheckContents(headers.map(([id]) => id)).pipe(
+              Effect.flatMap((getContent) =>
+                Effect.all(
+                  headers.map(([id, header]) =>
                     Schema.decodeUnknownEither(TemperatureCheckSchema)({
-                      id: key,
-                      ...value
+                      id,
+                      ...header,
+                      ...getContent(id)
                     })
                   )
                 )
               )
             )
-          ),
-          Effect.flatMap(Effect.all)
+          )
         )
 
       const getTemperatureCheckById = (id: TemperatureCheckId) =>
@@ -172,16 +255,16 @@ export class GovernanceComponent extends Effect.Service<GovernanceComponent>()(
             ),
             Effect.flatMap((sbor) => {
               return parseSbor(sbor, TemperatureCheckKeyValueStoreValue)
-            }),
-            Effect.flatMap((parsed) => {
-              return Schema.decodeUnknown(TemperatureCheckSchema)({
-                ...parsed,
-                id
-              })
             })
           )
 
-          return temperatureCheck
+          const getContent = yield* getTemperatureCheckContents([id])
+
+          return yield* Schema.decodeUnknown(TemperatureCheckSchema)({
+            ...temperatureCheck,
+            ...getContent(id),
+            id
+          })
         })
 
       const getAllTemperatureChecksVotes = (input: {
@@ -227,8 +310,42 @@ export class GovernanceComponent extends Effect.Service<GovernanceComponent>()(
             MakeTemperatureCheckInputSchema
           )(input)
 
+          // Check the on-ledger byte limits so the transaction is not rejected
+          const limits = yield* getDraftLimits()
+          const exceeded = [
+            {
+              field: 'Title',
+              value: parsedInput.title,
+              max: limits.max_title_bytes
+            },
+            {
+              field: 'Short description',
+              value: parsedInput.shortDescription,
+              max: limits.max_short_description_bytes
+            },
+            {
+              field: 'Description',
+              value: parsedInput.description,
+              max: limits.max_inline_description_bytes
+            },
+            ...parsedInput.voteOptions.map((label) => ({
+              field: 'Vote option label',
+              value: label,
+              max: limits.max_option_label_bytes
+            }))
+          ].find(({ value, max }) => utf8ByteLength(value) > max)
+          if (exceeded) {
+            return yield* new DraftLimitExceededError({
+              message: `${exceeded.field} must be ${exceeded.max} bytes or less`
+            })
+          }
+
+          // Label, then no option description, color or link
           const voteOptions = parsedInput.voteOptions
-            .map((option) => `Tuple("${option}")`)
+            .map(
+              (option) =>
+                `Tuple(${JSON.stringify(option)}, Enum<0u8>(), Enum<0u8>(), Enum<0u8>())`
+            )
             .join(', ')
 
           const links = parsedInput.links.map((url) => `"${url}"`).join(', ')
@@ -246,10 +363,15 @@ CALL_METHOD
   Tuple(
     "${parsedInput.title}",
     "${parsedInput.shortDescription}",
-    ${JSON.stringify(parsedInput.description)},
+    Enum<0u8>(${JSON.stringify(parsedInput.description)}), # inline description
     Array<Tuple>(${voteOptions}),
     Array<String>(${links}),
-    ${maxSelectionsManifest}
+    Enum<0u8>(), # no RFC reference
+    Array<Tuple>(), # no attachments
+    ${maxSelectionsManifest},
+    Enum<0u8>(), # no minimum selections
+    Enum<0u8>(), # open to every account
+    Enum<0u8>() # voting opens immediately
   )
 ;
 CALL_METHOD
@@ -275,6 +397,8 @@ CALL_METHOD
               Address("${parsedInput.accountAddress}") # account to vote with
               ${parsedInput.temperatureCheckId}u64 # temperature check id
               Enum<${parsedInput.vote === 'For' ? 0 : 1}u8>() # for or against temp check, this is "for", Enum<1u8>() would be "against"
+              None # optional rationale
+              None # eligibility proof, only gated temperature checks need one
             ;
 
             CALL_METHOD
@@ -357,16 +481,9 @@ CALL_METHOD
             Effect.flatMap(
               Effect.forEach(
                 Effect.fnUntraced(function* (item) {
-                  const { accountAddress, vote, replacingVoteId } =
-                    yield* Schema.decodeUnknown(TemperatureCheckVoteRecord)(
-                      item.value.programmatic_json
-                    )
-
-                  return {
-                    accountAddress,
-                    vote,
-                    replacingVoteId
-                  }
+                  return yield* Schema.decodeUnknown(
+                    TemperatureCheckVoteRecord
+                  )(item.value.programmatic_json)
                 }),
                 { concurrency: 10 }
               )
@@ -401,16 +518,9 @@ CALL_METHOD
             Effect.flatMap(
               Effect.forEach(
                 Effect.fnUntraced(function* (item) {
-                  const { accountAddress, options, replacingVoteId } =
-                    yield* Schema.decodeUnknown(ProposalVoteRecord)(
-                      item.value.programmatic_json
-                    )
-
-                  return {
-                    accountAddress,
-                    options,
-                    replacingVoteId
-                  }
+                  return yield* Schema.decodeUnknown(ProposalVoteRecord)(
+                    item.value.programmatic_json
+                  )
                 }),
                 { concurrency: 10 }
               )
@@ -463,16 +573,18 @@ CALL_METHOD
             ),
             Effect.flatMap((sbor) => {
               return parseSbor(sbor, ProposalKeyValueStoreValue)
-            }),
-            Effect.flatMap((parsed) => {
-              return Schema.decodeUnknown(ProposalSchema)({
-                ...parsed,
-                id
-              })
             })
           )
 
-          return proposal
+          const getContent = yield* getTemperatureCheckContents([
+            proposal.temperature_check_id
+          ])
+
+          return yield* Schema.decodeUnknown(ProposalSchema)({
+            ...proposal,
+            ...getContent(proposal.temperature_check_id),
+            id
+          })
         })
 
       const getPaginatedTemperatureChecks = (input: {
@@ -558,13 +670,9 @@ CALL_METHOD
                           sbor,
                           TemperatureCheckKeyValueStoreValue
                         ).pipe(
-                          Effect.flatMap((parsed) =>
-                            Schema.decodeUnknown(TemperatureCheckSchema)({
-                              ...parsed,
-                              id: ids[index]
-                            })
-                          ),
-                          Effect.map(Option.some)
+                          Effect.map((parsed) =>
+                            Option.some([ids[index], parsed] as const)
+                          )
                         )
                     })
                   )
@@ -572,7 +680,22 @@ CALL_METHOD
                 { concurrency: 'unbounded' }
               )
             ),
-            Effect.map(A.filterMap((x) => x))
+            Effect.map(A.filterMap((x) => x)),
+            Effect.flatMap((headers) =>
+              getTemperatureCheckContents(headers.map(([id]) => id)).pipe(
+                Effect.flatMap((getContent) =>
+                  Effect.all(
+                    headers.map(([id, header]) =>
+                      Schema.decodeUnknown(TemperatureCheckSchema)({
+                        ...header,
+                        ...getContent(id),
+                        id
+                      })
+                    )
+                  )
+                )
+              )
+            )
           )
 
           return {
@@ -658,13 +781,9 @@ CALL_METHOD
                       onNone: () => Effect.succeed(Option.none()),
                       onSome: (sbor) =>
                         parseSbor(sbor, ProposalKeyValueStoreValue).pipe(
-                          Effect.flatMap((parsed) =>
-                            Schema.decodeUnknown(ProposalSchema)({
-                              ...parsed,
-                              id: ids[index]
-                            })
-                          ),
-                          Effect.map(Option.some)
+                          Effect.map((parsed) =>
+                            Option.some([ids[index], parsed] as const)
+                          )
                         )
                     })
                   )
@@ -672,7 +791,24 @@ CALL_METHOD
                 { concurrency: 'unbounded' }
               )
             ),
-            Effect.map(A.filterMap((x) => x))
+            Effect.map(A.filterMap((x) => x)),
+            Effect.flatMap((headers) =>
+              getTemperatureCheckContents(
+                headers.map(([, header]) => header.temperature_check_id)
+              ).pipe(
+                Effect.flatMap((getContent) =>
+                  Effect.all(
+                    headers.map(([id, header]) =>
+                      Schema.decodeUnknown(ProposalSchema)({
+                        ...header,
+                        ...getContent(header.temperature_check_id),
+                        id
+                      })
+                    )
+                  )
+                )
+              )
+            )
           )
 
           return {
@@ -701,6 +837,7 @@ CALL_METHOD
   Address("${config.componentAddress}")
   "make_proposal"
   ${input.temperatureCheckId}u64
+  None
 ;
           `)
         )
@@ -756,6 +893,11 @@ CALL_METHOD
           Effect.map((state) => state.governance_parameters)
         )
 
+      const getDraftLimits = () =>
+        getGovernanceParameters().pipe(
+          Effect.map((parameters) => parameters.draft_limits)
+        )
+
       const makeUpdateGovernanceParametersManifest = (
         input: MakeUpdateGovernanceParametersInput
       ) =>
@@ -764,6 +906,43 @@ CALL_METHOD
             MakeUpdateGovernanceParametersInputSchema
           )(input)
 
+          // The method replaces the whole struct, so the fields the admin page
+          // does not edit are sent back with their current values
+          const current = yield* getGovernanceParameters()
+
+          const antiSniping =
+            current.anti_sniping.variant === 'Some'
+              ? `Enum<1u8>(Tuple(${current.anti_sniping.value[0].window_minutes}u32, ${current.anti_sniping.value[0].extension_minutes}u32, ${current.anti_sniping.value[0].max_extension_minutes}u32))`
+              : 'Enum<0u8>()'
+
+          const revotePolicy = (() => {
+            switch (current.revote_policy.variant) {
+              case 'NoRevotes':
+                return 'Enum<0u8>()'
+              case 'Unlimited':
+                return 'Enum<1u8>()'
+              case 'AtMost':
+                return `Enum<2u8>(${current.revote_policy.value[0]}u32)`
+              case 'UntilLockWindow':
+                return `Enum<3u8>(${current.revote_policy.value.lock_minutes}u32)`
+            }
+          })()
+
+          const limits = current.draft_limits
+          const draftLimits = `Tuple(${limits.max_title_bytes}u32, ${limits.max_short_description_bytes}u32, ${limits.max_inline_description_bytes}u32, ${limits.max_option_label_bytes}u32, ${limits.max_option_description_bytes}u32)`
+
+          const abstainOptionLabel =
+            current.abstain_option_label.variant === 'Some'
+              ? `Enum<1u8>(${JSON.stringify(current.abstain_option_label.value[0])})`
+              : 'Enum<0u8>()'
+
+          const powerMode = `Enum<${[
+            'Unweighted',
+            'MerkleSnapshot',
+            'LsuProofs',
+            'VoteEscrow'
+          ].indexOf(current.power_mode.variant)}u8>()`
+
           return TransactionManifestString.make(`
 CALL_METHOD
   Address("${parsedInput.accountAddress}")
@@ -774,7 +953,22 @@ CALL_METHOD
 CALL_METHOD
   Address("${config.componentAddress}")
   "update_governance_parameters"
-  Tuple(${parsedInput.temperatureCheckDays}u16, Decimal("${parsedInput.temperatureCheckQuorum}"), Decimal("${parsedInput.temperatureCheckApprovalThreshold}"), ${parsedInput.proposalLengthDays}u16, Decimal("${parsedInput.proposalQuorum}"), Decimal("${parsedInput.proposalApprovalThreshold}"))
+  Tuple(
+    ${parsedInput.temperatureCheckLengthMinutes}u32,
+    Decimal("${parsedInput.temperatureCheckQuorum}"),
+    Decimal("${parsedInput.temperatureCheckApprovalThreshold}"),
+    ${parsedInput.proposalLengthMinutes}u32,
+    Decimal("${parsedInput.proposalQuorum}"),
+    Decimal("${parsedInput.proposalApprovalThreshold}"),
+    ${current.max_start_delay_minutes}u32,
+    ${current.max_deadline_extension_minutes}u32,
+    ${antiSniping},
+    ${revotePolicy},
+    ${current.max_rationale_length}u32,
+    ${draftLimits},
+    ${abstainOptionLabel},
+    ${powerMode}
+  )
 ;
           `)
         })
@@ -796,6 +990,8 @@ CALL_METHOD
   Address("${parsedInput.accountAddress}")
   ${parsedInput.proposalId}u64
   Array<Tuple>(${optionIds})
+  None
+  None
 ;
 CALL_METHOD
   Address("${parsedInput.accountAddress}")
@@ -854,6 +1050,7 @@ CALL_METHOD
         getTemperatureCheckVotesByAccounts,
         getGovernanceState,
         getGovernanceParameters,
+        getDraftLimits,
         makeUpdateGovernanceParametersManifest,
         getProposalById,
         getPaginatedTemperatureChecks,
@@ -22,13 +22,110 @@ export const MakeTemperatureCheckInputSchema = Schema.Struct({
 export type MakeTemperatureCheckInput =
   typeof MakeTemperatureCheckInputSchema.Encoded
 
+// Length of a string in UTF-8 bytes, the unit of the on-ledger draft limits
+export const utf8ByteLength = (value: string) =>
+  new TextEncoder().encode(value).length
+
+const DescriptionSchema = Schema.Union(
+  Schema.Struct({
+    variant: Schema.Literal('Inline'),
+    value: Schema.Tuple(Schema.String)
+  }),
+  Schema.Struct({
+    variant: Schema.Literal('Ref'),
+    value: Schema.Tuple(
+      Schema.Struct({
+        hash: Schema.String,
+        location: Schema.Union(
+          Schema.Struct({
+            variant: Schema.Literal('None'),
+            value: Schema.Struct({})
+          }),
+          Schema.Struct({
+            variant: Schema.Literal('Some'),
+            value: Schema.Tuple(
+              Schema.Struct({
+                variant: Schema.Literal('Uri', 'IpfsCid'),
+                value: Schema.Tuple(Schema.String)
+              })
+            )
+          })
+        )
+      })
+    )
+  })
+)
+
+/**
+ * Off-ledger description, to be verified against `hash` (Blake2b-256) once fetched
+ */
+const ContentRefSchema = Schema.Struct({
+  hash: Schema.String,
+  location: Schema.OptionFromSelf(
+    Schema.Struct({
+      kind: Schema.Literal('Uri', 'IpfsCid'),
+      value: Schema.String
+    })
+  )
+})
+
+type ContentRef = typeof ContentRefSchema.Type
+
+// Inline descriptions are returned as is; referenced ones leave `description` empty
+const decodeDescription = (
+  description: typeof DescriptionSchema.Type
+): { description: string; descriptionRef: Option.Option<ContentRef> } =>
+  description.variant === 'Inline'
+    ? { description: description.value[0], descriptionRef: Option.none() }
+    : {
+        description: '',
+        descriptionRef: Option.some({
+          hash: description.value[0].hash,
+          location:
+            description.value[0].location.variant === 'Some'
+              ? Option.some({
+                  kind: description.value[0].location.value[0].variant,
+                  value: description.value[0].location.value[0].value[0]
+                })
+              : Option.none()
+        })
+      }
+
+const encodeDescription = (values: {
+  description: string
+  descriptionRef: Option.Option<ContentRef>
+}): typeof DescriptionSchema.Type =>
+  Option.match(values.descriptionRef, {
+    onNone: () => ({
+      variant: 'Inline' as const,
+      value: [values.description] as const
+    }),
+    onSome: (ref) => ({
+      variant: 'Ref' as const,
+      value: [
+        {
+          hash: ref.hash,
+          location: Option.match(ref.location, {
+            onNone: () => ({ variant: 'None' as const, value: {} }),
+            onSome: (location) => ({
+              variant: 'Some' as const,
+              value: [
+                { variant: location.kind, value: [location.value] as const }
+              ] as const
+            })
+          })
+        }
+      ] as const
+    })
+  })
+
 export const TemperatureCheckSchema = Schema.asSchema(
   Schema.transform(
     Schema.Struct({
       id: Schema.Number,
       title: Schema.String,
       short_description: Schema.String,
-      description: Schema.String,
+      description: DescriptionSchema,
       voters: Schema.String,
       votes: Schema.String,
       vote_count: Schema.Number,
@@ -55,6 +152,7 @@ export const TemperatureCheckSchema = Schema.asSchema(
       title: Schema.String,
       shortDescription: Schema.String,
       description: Schema.String,
+      descriptionRef: Schema.OptionFromSelf(ContentRefSchema),
       voters: KeyValueStoreAddress,
       votes: KeyValueStoreAddress,
       voteCount: Schema.Number,
@@ -78,7 +176,7 @@ export const TemperatureCheckSchema = Schema.asSchema(
         id: fromA.id,
         title: fromA.title,
         shortDescription: fromA.short_description,
-        description: fromA.description,
+        ...decodeDescription(fromA.description),
         voters: KeyValueStoreAddress.make(fromA.voters),
         votes: KeyValueStoreAddress.make(fromA.votes),
         voteCount: fromA.vote_count,
@@ -104,7 +202,7 @@ export const TemperatureCheckSchema = Schema.asSchema(
         id: values.id,
         title: values.title,
         short_description: values.shortDescription,
-        description: values.description,
+        description: encodeDescription(values),
         voters: values.voters,
         votes: values.votes,
         vote_count: values.voteCount,
@@ -137,7 +235,7 @@ export const ProposalSchema = Schema.asSchema(
       id: Schema.Number,
       title: Schema.String,
       short_description: Schema.String,
-      description: Schema.String,
+      description: DescriptionSchema,
       voters: Schema.String,
       votes: Schema.String,
       vote_count: Schema.Number,
@@ -171,6 +269,7 @@ export const ProposalSchema = Schema.asSchema(
       title: Schema.String,
       shortDescription: Schema.String,
       description: Schema.String,
+      descriptionRef: Schema.OptionFromSelf(ContentRefSchema),
       voters: KeyValueStoreAddress,
       votes: KeyValueStoreAddress,
       voteCount: Schema.Number,
@@ -195,7 +294,7 @@ export const ProposalSchema = Schema.asSchema(
         id: fromA.id,
         title: fromA.title,
         shortDescription: fromA.short_description,
-        description: fromA.description,
+        ...decodeDescription(fromA.description),
         voters: KeyValueStoreAddress.make(fromA.voters),
         votes: KeyValueStoreAddress.make(fromA.votes),
         voteCount: fromA.vote_count,
@@ -220,7 +319,7 @@ export const ProposalSchema = Schema.asSchema(
         id: values.id,
         title: values.title,
         short_description: values.shortDescription,
-        description: values.description,
+        description: encodeDescription(values),
         voters: values.voters,
         votes: values.votes,
         vote_count: values.voteCount,
@@ -255,27 +354,48 @@ export const TemperatureCheckVoteSchema = Schema.transform(
   Schema.Struct({
     id: Schema.Number,
     voter: Schema.String,
-    vote: Schema.Struct({
-      variant: Schema.String,
-      value: Schema.Tuple()
-    })
+    vote: Schema.Union(
+      Schema.Struct({
+        variant: Schema.Literal('None'),
+        value: Schema.Struct({})
+      }),
+      Schema.Struct({
+        variant: Schema.Literal('Some'),
+        value: Schema.Tuple(
+          Schema.Struct({
+            variant: Schema.Literal('For', 'Against'),
+            value: Schema.Tuple()
+          })
+        )
+      })
+    )
   }),
   Schema.Struct({
     id: Schema.Number,
     voter: AccountAddress,
-    vote: Schema.Literal('For', 'Against')
+    // None for a retraction record
+    vote: Schema.OptionFromSelf(Schema.Literal('For', 'Against'))
   }),
   {
     strict: true,
     decode: (fromA) => ({
       id: fromA.id,
       voter: AccountAddress.make(fromA.voter),
-      vote: fromA.vote.variant as 'For' | 'Against'
+      vote:
+        fromA.vote.variant === 'Some'
+          ? Option.some(fromA.vote.value[0].variant)
+          : Option.none()
     }),
     encode: (values) => ({
       id: values.id,
       voter: values.voter,
-      vote: { variant: values.vote, value: [] as const }
+      vote: Option.match(values.vote, {
+        onNone: () => ({ variant: 'None' as const, value: {} }),
+        onSome: (vote) => ({
+          variant: 'Some' as const,
+          value: [{ variant: vote, value: [] as const }] as const
+        })
+      })
     })
   }
 )
@@ -299,17 +419,17 @@ export type MakeProposalVoteInput = typeof MakeProposalVoteInputSchema.Encoded
 
 export const MakeUpdateGovernanceParametersInputSchema = Schema.Struct({
   accountAddress: AccountAddress,
-  temperatureCheckDays: Schema.Number.pipe(
+  temperatureCheckLengthMinutes: Schema.Number.pipe(
     Schema.int(),
-    Schema.between(1, 65535)
+    Schema.between(1, 4294967295)
   ),
   temperatureCheckQuorum: Schema.NumberFromString,
   temperatureCheckApprovalThreshold: Schema.NumberFromString.pipe(
     Schema.between(0, 1)
   ),
-  proposalLengthDays: Schema.Number.pipe(
+  proposalLengthMinutes: Schema.Number.pipe(
     Schema.int(),
-    Schema.between(1, 65535)
+    Schema.between(1, 4294967295)
   ),
   proposalQuorum: Schema.NumberFromString,
   proposalApprovalThreshold: Schema.NumberFromString.pipe(
@@ -342,6 +462,12 @@ export const TemperatureCheckVoteValueSchema = Schema.asSchema(
             s.enum([
               { variant: 'For', schema: s.structNullable({}) },
               { variant: 'Against', schema: s.structNullable({}) }
+            ]),
+            s.number(),
+            s.decimal(),
+            s.enum([
+              { variant: 'None', schema: s.structNullable({}) },
+              { variant: 'Some', schema: s.tuple([s.nonFungibleLocalId()]) }
             ])
           ])
         ).pipe(
@@ -358,13 +484,27 @@ export const TemperatureCheckVoteValueSchema = Schema.asSchema(
   )
 )
 
+const VoteRationaleSchema = Schema.Struct({
+  kind: Schema.Literal('Text', 'Url'),
+  value: Schema.String
+})
+
+type VoteRationale = typeof VoteRationaleSchema.Type
+
+/**
+ * A record in the `votes` KVS. `vote` is None for a retraction record,
+ * which supersedes the account's earlier records like a revote does.
+ */
 export const TemperatureCheckVoteRecord = Schema.asSchema(
   Schema.transformOrFail(
     ProgrammaticScryptoSborValueSchema,
     Schema.Struct({
       accountAddress: AccountAddress,
-      vote: Schema.Literal('For', 'Against'),
-      replacingVoteId: Schema.OptionFromSelf(Schema.Number)
+      vote: Schema.OptionFromSelf(Schema.Literal('For', 'Against')),
+      replacingVoteId: Schema.OptionFromSelf(Schema.Number),
+      rationale: Schema.OptionFromSelf(VoteRationaleSchema),
+      power: Schema.String,
+      positionId: Schema.OptionFromSelf(Schema.String)
     }),
     {
       strict: true,
@@ -374,23 +514,65 @@ export const TemperatureCheckVoteRecord = Schema.asSchema(
           s.tuple([
             s.address(),
             s.enum([
-              { variant: 'For', schema: s.structNullable({}) },
-              { variant: 'Against', schema: s.structNullable({}) }
+              { variant: 'None', schema: s.structNullable({}) },
+              {
+                variant: 'Some',
+                schema: s.tuple([
+                  s.enum([
+                    { variant: 'For', schema: s.structNullable({}) },
+                    { variant: 'Against', schema: s.structNullable({}) }
+                  ])
+                ])
+              }
             ]),
             s.enum([
               { variant: 'None', schema: s.structNullable({}) },
               { variant: 'Some', schema: s.tuple([s.number()]) }
+            ]),
+            s.enum([
+              { variant: 'None', schema: s.structNullable({}) },
+              {
+                variant: 'Some',
+                schema: s.tuple([
+                  s.enum([
+                    { variant: 'Text', schema: s.tuple([s.string()]) },
+                    { variant: 'Url', schema: s.tuple([s.string()]) }
+                  ])
+                ])
+              }
+            ]),
+            s.decimal(),
+            s.enum([
+              { variant: 'None', schema: s.structNullable({}) },
+              { variant: 'Some', schema: s.tuple([s.nonFungibleLocalId()]) }
             ])
           ])
         ).pipe(
-          Effect.map(([address, vote, replacingVoteId]) => ({
-            accountAddress: AccountAddress.make(address),
-            vote: vote.variant as 'For' | 'Against',
-            replacingVoteId:
-              replacingVoteId.variant === 'Some'
-                ? Option.some(replacingVoteId.value[0])
-                : Option.none<number>()
-          })),
+          Effect.map(
+            ([address, vote, replacingVoteId, rationale, power, positionId]) => ({
+              accountAddress: AccountAddress.make(address),
+              vote:
+                vote.variant === 'Some'
+                  ? Option.some(vote.value[0].variant as 'For' | 'Against')
+                  : Option.none<'For' | 'Against'>(),
+              replacingVoteId:
+                replacingVoteId.variant === 'Some'
+                  ? Option.some(replacingVoteId.value[0])
+                  : Option.none<number>(),
+              rationale:
+                rationale.variant === 'Some'
+                  ? Option.some({
+                      kind: rationale.value[0].variant as 'Text' | 'Url',
+                      value: rationale.value[0].value[0]
+                    })
+                  : Option.none<VoteRationale>(),
+              power,
+              positionId:
+                positionId.variant === 'Some'
+                  ? Option.some(positionId.value[0])
+                  : Option.none<string>()
+            })
+          ),
           Effect.catchAll(() =>
             ParseResult.fail(
               new ParseResult.Type(ast, value, `Invalid vote value: ${value}`)
@@ -412,7 +594,16 @@ export const ProposalVoteValueSchema = Schema.asSchema(
       decode: (value, _, ast) =>
         parseSbor(
           value,
-          s.tuple([s.number(), s.array(s.tuple([s.number()]))])
+          s.tuple([
+            s.number(),
+            s.array(s.tuple([s.number()])),
+            s.number(),
+            s.decimal(),
+            s.enum([
+              { variant: 'None', schema: s.structNullable({}) },
+              { variant: 'Some', schema: s.tuple([s.nonFungibleLocalId()]) }
+            ])
+          ])
         ).pipe(
           Effect.map((result) => result[1].map((option) => option[0])),
           Effect.catchAll(() =>
@@ -431,13 +622,19 @@ export const ProposalVoteValueSchema = Schema.asSchema(
   )
 )
 
+/**
+ * A record in the `votes` KVS. `options` is None for a retraction record.
+ */
 export const ProposalVoteRecord = Schema.asSchema(
   Schema.transformOrFail(
     ProgrammaticScryptoSborValueSchema,
     Schema.Struct({
       accountAddress: AccountAddress,
-      options: Schema.Array(Schema.Number),
-      replacingVoteId: Schema.OptionFromSelf(Schema.Number)
+      options: Schema.OptionFromSelf(Schema.Array(Schema.Number)),
+      replacingVoteId: Schema.OptionFromSelf(Schema.Number),
+      rationale: Schema.OptionFromSelf(VoteRationaleSchema),
+      power: Schema.String,
+      positionId: Schema.OptionFromSelf(Schema.String)
     }),
     {
       strict: true,
@@ -446,21 +643,61 @@ export const ProposalVoteRecord = Schema.asSchema(
           value,
           s.tuple([
             s.address(),
-            s.array(s.tuple([s.number()])),
+            s.enum([
+              { variant: 'None', schema: s.structNullable({}) },
+              {
+                variant: 'Some',
+                schema: s.tuple([s.array(s.tuple([s.number()]))])
+              }
+            ]),
             s.enum([
               { variant: 'None', schema: s.structNullable({}) },
               { variant: 'Some', schema: s.tuple([s.number()]) }
+            ]),
+            s.enum([
+              { variant: 'None', schema: s.structNullable({}) },
+              {
+                variant: 'Some',
+                schema: s.tuple([
+                  s.enum([
+                    { variant: 'Text', schema: s.tuple([s.string()]) },
+                    { variant: 'Url', schema: s.tuple([s.string()]) }
+                  ])
+                ])
+              }
+            ]),
+            s.decimal(),
+            s.enum([
+              { variant: 'None', schema: s.structNullable({}) },
+              { variant: 'Some', schema: s.tuple([s.nonFungibleLocalId()]) }
             ])
           ])
         ).pipe(
-          Effect.map(([address, options, replacingVoteId]) => ({
-            accountAddress: AccountAddress.make(address),
-            options: options.map((option) => option[0]),
-            replacingVoteId:
-              replacingVoteId.variant === 'Some'
-                ? Option.some(replacingVoteId.value[0])
-                : Option.none<number>()
-          })),
+          Effect.map(
+            ([address, options, replacingVoteId, rationale, power, positionId]) => ({
+              accountAddress: AccountAddress.make(address),
+              options:
+                options.variant === 'Some'
+                  ? Option.some(options.value[0].map((option) => option[0]))
+                  : Option.none<ReadonlyArray<number>>(),
+              replacingVoteId:
+                replacingVoteId.variant === 'Some'
+                  ? Option.some(replacingVoteId.value[0])
+                  : Option.none<number>(),
+              rationale:
+                rationale.variant === 'Some'
+                  ? Option.some({
+                      kind: rationale.value[0].variant as 'Text' | 'Url',
+                      value: rationale.value[0].value[0]
+                    })
+                  : Option.none<VoteRationale>(),
+              power,
+              positionId:
+                positionId.variant === 'Some'
+                  ? Option.some(positionId.value[0])
+                  : Option.none<string>()
+            })
+          ),
           Effect.catchAll(() =>
             ParseResult.fail(
               new ParseResult.Type(
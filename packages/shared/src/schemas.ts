@@ -9,66 +9,282 @@ export const VoteDelegation = s.struct({
   delegators: s.internalAddress()
 })
 
+export const AntiSnipingRule = s.struct({
+  window_minutes: s.number(),
+  extension_minutes: s.number(),
+  max_extension_minutes: s.number()
+})
+
+export const RevotePolicy = s.enum([
+  { variant: 'NoRevotes', schema: s.structNullable({}) },
+  { variant: 'Unlimited', schema: s.structNullable({}) },
+  { variant: 'AtMost', schema: s.tuple([s.number()]) },
+  {
+    variant: 'UntilLockWindow',
+    schema: s.struct({ lock_minutes: s.number() })
+  }
+])
+
+export const DraftLimits = s.struct({
+  max_title_bytes: s.number(),
+  max_short_description_bytes: s.number(),
+  max_inline_description_bytes: s.number(),
+  max_option_label_bytes: s.number(),
+  max_option_description_bytes: s.number()
+})
+
+export const PowerMode = s.enum([
+  { variant: 'Unweighted', schema: s.structNullable({}) },
+  { variant: 'MerkleSnapshot', schema: s.structNullable({}) },
+  { variant: 'LsuProofs', schema: s.structNullable({}) },
+  { variant: 'VoteEscrow', schema: s.structNullable({}) }
+])
+
 export const GovernanceParameters = s.struct({
-  temperature_check_days: s.number(),
+  temperature_check_length_minutes: s.number(),
   temperature_check_quorum: s.decimal(),
   temperature_check_approval_threshold: s.decimal(),
-  proposal_length_days: s.number(),
+  proposal_length_minutes: s.number(),
   proposal_quorum: s.decimal(),
-  proposal_approval_threshold: s.decimal()
+  proposal_approval_threshold: s.decimal(),
+  max_start_delay_minutes: s.number(),
+  max_deadline_extension_minutes: s.number(),
+  anti_sniping: s.enum([
+    { variant: 'None', schema: s.structNullable({}) },
+    { variant: 'Some', schema: s.tuple([AntiSnipingRule]) }
+  ]),
+  revote_policy: RevotePolicy,
+  max_rationale_length: s.number(),
+  draft_limits: DraftLimits,
+  abstain_option_label: s.enum([
+    { variant: 'None', schema: s.structNullable({}) },
+    { variant: 'Some', schema: s.tuple([s.string()]) }
+  ]),
+  power_mode: PowerMode
+})
+
+export const MultisigConfig = s.struct({
+  admin_badge: s.address(),
+  threshold: s.number(),
+  action_validity_days: s.number()
+})
+
+export const PauseFlags = s.struct({
+  creation: s.bool(),
+  temperature_check_voting: s.bool(),
+  proposal_voting: s.bool()
+})
+
+export const PowerSource = s.struct({
+  address: s.address(),
+  kind: s.enum([
+    { variant: 'Xrd', schema: s.structNullable({}) },
+    { variant: 'Lsu', schema: s.structNullable({}) },
+    { variant: 'PoolUnit', schema: s.structNullable({}) },
+    { variant: 'LiquidityPosition', schema: s.structNullable({}) }
+  ]),
+  weight: s.decimal()
 })
 
 export const Governance = s.struct({
   governance_parameters: GovernanceParameters,
   temperature_checks: s.internalAddress(),
+  temperature_check_contents: s.internalAddress(),
   temperature_check_count: s.number(),
   proposals: s.internalAddress(),
-  proposal_count: s.number()
+  proposal_count: s.number(),
+  multisig_config: s.enum([
+    { variant: 'None', schema: s.structNullable({}) },
+    { variant: 'Some', schema: s.tuple([MultisigConfig]) }
+  ]),
+  pending_actions: s.internalAddress(),
+  pending_action_count: s.number(),
+  pause_flags: PauseFlags,
+  paused_at: s.enum([
+    { variant: 'None', schema: s.structNullable({}) },
+    { variant: 'Some', schema: s.tuple([s.instant()]) }
+  ]),
+  temperature_check_voting_paused_at: s.enum([
+    { variant: 'None', schema: s.structNullable({}) },
+    { variant: 'Some', schema: s.tuple([s.instant()]) }
+  ]),
+  proposal_voting_paused_at: s.enum([
+    { variant: 'None', schema: s.structNullable({}) },
+    { variant: 'Some', schema: s.tuple([s.instant()]) }
+  ]),
+  attestor_badge: s.address(),
+  account_votes: s.internalAddress(),
+  authors: s.internalAddress(),
+  escrow_position_resource: s.address(),
+  escrow_positions: s.internalAddress(),
+  escrow_position_count: s.number(),
+  escrow_vaults: s.internalAddress(),
+  power_sources: s.array(PowerSource),
+  power_source_snapshots: s.internalAddress()
 })
 
-export const TemperatureCheckKeyValueStoreKey = s.number()
+export const ProposalVoteOptionId = s.tuple([s.number()])
 
-export const TemperatureCheckKeyValueStoreValue = s.struct({
+export const EligibilityRule = s.enum([
+  {
+    variant: 'Resource',
+    schema: s.struct({ resource: s.address(), min_amount: s.decimal() })
+  },
+  {
+    variant: 'NonFungible',
+    schema: s.tuple([s.tuple([s.address(), s.nonFungibleLocalId()])])
+  }
+])
+
+export const Cancellation = s.struct({
+  cancelled_by: s.enum([
+    { variant: 'Author', schema: s.tuple([s.address()]) },
+    { variant: 'Owner', schema: s.structNullable({}) },
+    { variant: 'Admins', schema: s.struct({ action_id: s.number() }) }
+  ]),
+  reason: s.enum([
+    { variant: 'None', schema: s.structNullable({}) },
+    { variant: 'Some', schema: s.tuple([s.string()]) }
+  ]),
+  cancelled_at: s.number()
+})
+
+export const Description = s.enum([
+  { variant: 'Inline', schema: s.tuple([s.string()]) },
+  {
+    variant: 'Ref',
+    schema: s.tuple([
+      s.struct({
+        hash: s.string(),
+        location: s.enum([
+          { variant: 'None', schema: s.structNullable({}) },
+          {
+            variant: 'Some',
+            schema: s.tuple([
+              s.enum([
+                { variant: 'Uri', schema: s.tuple([s.string()]) },
+                { variant: 'IpfsCid', schema: s.tuple([s.string()]) }
+              ])
+            ])
+          }
+        ])
+      })
+    ])
+  }
+])
+
+export const ProposalVoteOption = s.struct({
+  id: ProposalVoteOptionId,
+  label: s.string(),
+  description: s.enum([
+    { variant: 'None', schema: s.structNullable({}) },
+    { variant: 'Some', schema: s.tuple([s.string()]) }
+  ]),
+  color: s.enum([
+    { variant: 'None', schema: s.structNullable({}) },
+    { variant: 'Some', schema: s.tuple([s.string()]) }
+  ]),
+  link: s.enum([
+    { variant: 'None', schema: s.structNullable({}) },
+    { variant: 'Some', schema: s.tuple([s.string()]) }
+  ]),
+  is_abstain: s.bool()
+})
+
+export const RfcReference = s.struct({
+  url: s.string(),
+  forum_thread_id: s.enum([
+    { variant: 'None', schema: s.structNullable({}) },
+    { variant: 'Some', schema: s.tuple([s.number()]) }
+  ])
+})
+
+export const Attachment = s.struct({
+  name: s.string(),
+  url: s.string(),
+  content_hash: s.string(),
+  media_type: s.string()
+})
+
+export const TemperatureCheckContentKeyValueStoreKey = s.number()
+
+export const ItemContent = s.struct({
   title: s.string(),
   short_description: s.string(),
-  description: s.string(),
-  vote_options: s.array(
-    s.struct({
-      id: s.tuple([s.number()]),
-      label: s.string()
-    })
-  ),
+  description: Description,
+  vote_options: s.array(ProposalVoteOption),
   links: s.array(s.string()),
+  rfc_reference: s.enum([
+    { variant: 'None', schema: s.structNullable({}) },
+    { variant: 'Some', schema: s.tuple([RfcReference]) }
+  ]),
+  attachments: s.array(Attachment)
+})
+
+export const TemperatureCheckKeyValueStoreKey = s.number()
+
+export const TemperatureCheckKeyValueStoreValue = s.struct({
+  vote_option_count: s.number(),
+  abstain_option_id: s.enum([
+    { variant: 'None', schema: s.structNullable({}) },
+    { variant: 'Some', schema: s.tuple([ProposalVoteOptionId]) }
+  ]),
+  tally: s.struct({
+    for_votes: s.number(),
+    against_votes: s.number()
+  }),
+  power_mode: PowerMode,
+  power_root: s.enum([
+    { variant: 'None', schema: s.structNullable({}) },
+    { variant: 'Some', schema: s.tuple([s.string()]) }
+  ]),
+  power_tally: s.struct({
+    for_power: s.decimal(),
+    against_power: s.decimal()
+  }),
   quorum: s.decimal(),
   max_selections: s.enum([
-    {
-      variant: 'None',
-      schema: s.structNullable({})
-    },
-    {
-      variant: 'Some',
-      schema: s.tuple([s.number()])
-    }
+    { variant: 'None', schema: s.structNullable({}) },
+    { variant: 'Some', schema: s.tuple([s.number()]) }
+  ]),
+  min_selections: s.enum([
+    { variant: 'None', schema: s.structNullable({}) },
+    { variant: 'Some', schema: s.tuple([s.number()]) }
+  ]),
+  eligibility: s.enum([
+    { variant: 'None', schema: s.structNullable({}) },
+    { variant: 'Some', schema: s.tuple([EligibilityRule]) }
   ]),
   voters: s.internalAddress(),
+  retracted_voters: s.internalAddress(),
+  position_voters: s.internalAddress(),
+  eligibility_voters: s.internalAddress(),
   votes: s.internalAddress(),
   vote_count: s.number(),
   revote_count: s.number(),
+  retraction_count: s.number(),
   approval_threshold: s.decimal(),
   start: s.number(),
   deadline: s.number(),
+  created_epoch: s.number(),
+  created_transaction_hash: s.string(),
+  deadline_extension_minutes: s.number(),
+  anti_sniping: s.enum([
+    { variant: 'None', schema: s.structNullable({}) },
+    { variant: 'Some', schema: s.tuple([AntiSnipingRule]) }
+  ]),
+  anti_sniping_extension_minutes: s.number(),
+  revote_policy: RevotePolicy,
   elevated_proposal_id: s.enum([
-    {
-      variant: 'None',
-      schema: s.structNullable({})
-    },
-    {
-      variant: 'Some',
-      schema: s.tuple([s.number()])
-    }
+    { variant: 'None', schema: s.structNullable({}) },
+    { variant: 'Some', schema: s.tuple([s.number()]) }
   ]),
   author: s.address(),
-  hidden: s.bool()
+  hidden: s.bool(),
+  cancellation: s.enum([
+    { variant: 'None', schema: s.structNullable({}) },
+    { variant: 'Some', schema: s.tuple([Cancellation]) }
+  ])
 })
 
 export const KeyValueStoreAddress = Schema.String.pipe(
@@ -84,9 +300,26 @@ export const TemperatureCheckVote = s.enum([
   { variant: 'Against', schema: s.tuple([]) }
 ])
 
+export const VoteRationale = s.enum([
+  { variant: 'None', schema: s.structNullable({}) },
+  {
+    variant: 'Some',
+    schema: s.tuple([
+      s.enum([
+        { variant: 'Text', schema: s.tuple([s.string()]) },
+        { variant: 'Url', schema: s.tuple([s.string()]) }
+      ])
+    ])
+  }
+])
+
 export const TemperatureCheckVoteKeyValueStoreValue = s.struct({
   voter: s.address(),
-  vote: TemperatureCheckVote,
+  // None for a retraction record
+  vote: s.enum([
+    { variant: 'None', schema: s.structNullable({}) },
+    { variant: 'Some', schema: s.tuple([TemperatureCheckVote]) }
+  ]),
   replacing_vote_id: s.enum([
     {
       variant: 'None',
@@ -96,59 +329,89 @@ export const TemperatureCheckVoteKeyValueStoreValue = s.struct({
       variant: 'Some',
       schema: s.tuple([s.number()])
     }
-  ])
+  ]),
+  rationale: VoteRationale,
+  power: s.decimal(),
+  position_ids: s.array(s.nonFungibleLocalId())
 })
 
 export const TemperatureCheckVotersKeyValueStoreKey = s.address()
 
 export const TemperatureCheckVotersKeyValueStoreValue = s.struct({
   vote_id: s.number(),
-  vote: TemperatureCheckVote
+  vote: TemperatureCheckVote,
+  revotes: s.number(),
+  power: s.decimal(),
+  position_ids: s.array(s.nonFungibleLocalId())
 })
 
 export const ProposalKeyValueStoreKey = s.number()
 
 export const ProposalKeyValueStoreValue = s.struct({
-  title: s.string(),
-  short_description: s.string(),
-  description: s.string(),
-  vote_options: s.array(
-    s.struct({
-      id: s.tuple([s.number()]),
-      label: s.string()
-    })
-  ),
-  links: s.array(s.string()),
+  vote_option_count: s.number(),
+  abstain_option_id: s.enum([
+    { variant: 'None', schema: s.structNullable({}) },
+    { variant: 'Some', schema: s.tuple([ProposalVoteOptionId]) }
+  ]),
+  option_tallies: s.array(s.number()),
+  power_mode: PowerMode,
+  power_root: s.enum([
+    { variant: 'None', schema: s.structNullable({}) },
+    { variant: 'Some', schema: s.tuple([s.string()]) }
+  ]),
+  option_powers: s.array(s.decimal()),
+  total_power: s.decimal(),
   quorum: s.decimal(),
   max_selections: s.enum([
-    {
-      variant: 'None',
-      schema: s.structNullable({})
-    },
-    {
-      variant: 'Some',
-      schema: s.tuple([s.number()])
-    }
+    { variant: 'None', schema: s.structNullable({}) },
+    { variant: 'Some', schema: s.tuple([s.number()]) }
+  ]),
+  min_selections: s.enum([
+    { variant: 'None', schema: s.structNullable({}) },
+    { variant: 'Some', schema: s.tuple([s.number()]) }
+  ]),
+  eligibility: s.enum([
+    { variant: 'None', schema: s.structNullable({}) },
+    { variant: 'Some', schema: s.tuple([EligibilityRule]) }
   ]),
   voters: s.internalAddress(),
+  retracted_voters: s.internalAddress(),
+  position_voters: s.internalAddress(),
+  eligibility_voters: s.internalAddress(),
   votes: s.internalAddress(),
   vote_count: s.number(),
   revote_count: s.number(),
+  retraction_count: s.number(),
   approval_threshold: s.decimal(),
   start: s.number(),
   deadline: s.number(),
+  created_epoch: s.number(),
+  created_transaction_hash: s.string(),
+  deadline_extension_minutes: s.number(),
+  anti_sniping: s.enum([
+    { variant: 'None', schema: s.structNullable({}) },
+    { variant: 'Some', schema: s.tuple([AntiSnipingRule]) }
+  ]),
+  anti_sniping_extension_minutes: s.number(),
+  revote_policy: RevotePolicy,
   temperature_check_id: s.number(),
   author: s.address(),
-  hidden: s.bool()
+  hidden: s.bool(),
+  cancellation: s.enum([
+    { variant: 'None', schema: s.structNullable({}) },
+    { variant: 'Some', schema: s.tuple([Cancellation]) }
+  ])
 })
 
-export const ProposalVoteOptionId = s.tuple([s.number()])
-
 export const ProposalVoteKeyValueStoreKey = s.number()
 
 export const ProposalVoteKeyValueStoreValue = s.struct({
   voter: s.address(),
-  options: s.array(ProposalVoteOptionId),
+  // None for a retraction record
+  options: s.enum([
+    { variant: 'None', schema: s.structNullable({}) },
+    { variant: 'Some', schema: s.tuple([s.array(ProposalVoteOptionId)]) }
+  ]),
   replacing_vote_id: s.enum([
     {
       variant: 'None',
@@ -158,14 +421,20 @@ export const ProposalVoteKeyValueStoreValue = s.struct({
       variant: 'Some',
       schema: s.tuple([s.number()])
     }
-  ])
+  ]),
+  rationale: VoteRationale,
+  power: s.decimal(),
+  position_ids: s.array(s.nonFungibleLocalId())
 })
 
 export const ProposalVotersKeyValueStoreKey = s.address()
 
 export const ProposalVotersKeyValueStoreValue = s.struct({
   vote_id: s.number(),
-  options: s.array(ProposalVoteOptionId)
+  options: s.array(ProposalVoteOptionId),
+  revotes: s.number(),
+  power: s.decimal(),
+  position_ids: s.array(s.nonFungibleLocalId())
 })
 
 export const TemperatureCheckVotedEvent = s.struct({
@@ -182,15 +451,33 @@ export const TemperatureCheckVotedEvent = s.struct({
       variant: 'Some',
       schema: s.tuple([s.number()])
     }
-  ])
+  ]),
+  rationale: VoteRationale,
+  power: s.decimal(),
+  position_ids: s.array(s.nonFungibleLocalId())
+})
+
+export const TemperatureCheckVoteRetractedEvent = s.struct({
+  temperature_check_id: s.number(),
+  vote_id: s.number(),
+  account: s.address(),
+  retracted_vote_id: s.number()
 })
 
 export const ProposalCreatedEvent = s.struct({
   proposal_id: s.number(),
   temperature_check_id: s.number(),
   title: s.string(),
+  vote_options: s.array(ProposalVoteOption),
+  power_sources: s.array(PowerSource),
   start: s.instant(),
-  deadline: s.instant()
+  deadline: s.instant(),
+  created_epoch: s.number(),
+  created_transaction_hash: s.string(),
+  eligibility: s.enum([
+    { variant: 'None', schema: s.structNullable({}) },
+    { variant: 'Some', schema: s.tuple([EligibilityRule]) }
+  ])
 })
 
 export const ProposalVotedEvent = s.struct({
@@ -207,12 +494,35 @@ export const ProposalVotedEvent = s.struct({
       variant: 'Some',
       schema: s.tuple([s.number()])
     }
-  ])
+  ]),
+  rationale: VoteRationale,
+  power: s.decimal(),
+  position_ids: s.array(s.nonFungibleLocalId())
+})
+
+export const ProposalVoteRetractedEvent = s.struct({
+  proposal_id: s.number(),
+  vote_id: s.number(),
+  account: s.address(),
+  retracted_vote_id: s.number()
 })
 
 export const TemperatureCheckCreatedEvent = s.struct({
   temperature_check_id: s.number(),
   title: s.string(),
+  rfc_reference: s.enum([
+    { variant: 'None', schema: s.structNullable({}) },
+    { variant: 'Some', schema: s.tuple([RfcReference]) }
+  ]),
+  attachments: s.array(Attachment),
+  vote_options: s.array(ProposalVoteOption),
+  power_sources: s.array(PowerSource),
   start: s.instant(),
-  deadline: s.instant()
+  deadline: s.instant(),
+  created_epoch: s.number(),
+  created_transaction_hash: s.string(),
+  eligibility: s.enum([
+    { variant: 'None', schema: s.structNullable({}) },
+    { variant: 'Some', schema: s.tuple([EligibilityRule]) }
+  ])
 })
@@ -1,9 +1,28 @@
+// The vote methods take six arguments, which the stubs generated by `#[blueprint]` push past
+// clippy's limit
+#![allow(clippy::too_many_arguments)]
+
 use crate::{
-    GovernanceParameters, GovernanceParametersUpdatedEvent, Proposal, ProposalCreatedEvent,
-    ProposalVoteOption, ProposalVoteOptionId, ProposalVoteRecord, ProposalVotedEvent,
-    ProposalVoterEntry, TemperatureCheck, TemperatureCheckCreatedEvent, TemperatureCheckDraft,
-    TemperatureCheckVote, TemperatureCheckVoteRecord, TemperatureCheckVotedEvent,
-    TemperatureCheckVoterEntry, MAX_LINKS, MAX_SELECTIONS, MAX_VOTE_OPTIONS,
+    verify_power_proof, AccountVote, AccountVoteIndex, ActionApprovedEvent, ActionCancelledEvent,
+    ActionExecutedEvent, ActionProposedEvent, AntiSnipingRule, AttestorUpdatedEvent, AuthorIndex,
+    AuthorItem, AuthorStats, BallotInput, BallotVote, Cancellation, CancelledBy, ContentLocation,
+    DeadlineExtendedEvent, DeadlineExtensionReason, Description, EligibilityHolding,
+    EligibilityRule, EscrowPosition, EscrowPositionData, EscrowPositionLockedEvent,
+    EscrowPositionWithdrawnEvent, GovernanceAction, GovernanceParameters,
+    GovernanceParametersUpdatedEvent, ItemContent, ItemKind, MerklePowerProof, MultisigConfig,
+    MultisigConfigUpdatedEvent, PauseFlags, PausedEvent, PendingAction, PendingActionStatus,
+    PowerClaim, PowerMode, PowerRootPostedEvent, PowerSource, PowerSourceRemovedEvent,
+    PowerSourceSetEvent, Proposal, ProposalCancelledEvent, ProposalCreatedEvent, ProposalResult,
+    ProposalView, ProposalVoteOption, ProposalVoteOptionId, ProposalVoteRecord,
+    ProposalVoteRetractedEvent, ProposalVotedEvent, ProposalVoterEntry, RevotePolicy,
+    TemperatureCheck, TemperatureCheckCancelledEvent, TemperatureCheckCreatedEvent,
+    TemperatureCheckDraft, TemperatureCheckPowerTally, TemperatureCheckResult,
+    TemperatureCheckTally, TemperatureCheckView, TemperatureCheckVote, TemperatureCheckVoteRecord,
+    TemperatureCheckVoteRetractedEvent, TemperatureCheckVotedEvent, TemperatureCheckVoterEntry,
+    UnpausedEvent, VoteRationale, VoteRecord, VoteView, ALLOWED_LINK_SCHEME, MAX_ATTACHMENTS,
+    MAX_ATTACHMENT_NAME_LENGTH, MAX_BALLOT_POSITIONS, MAX_BATCH_BALLOTS, MAX_ESCROW_LOCK_DAYS,
+    MAX_IPFS_CID_LENGTH, MAX_LINKS, MAX_LSU_PROOFS, MAX_MEDIA_TYPE_LENGTH, MAX_PAGE_SIZE,
+    MAX_POWER_SOURCES, MAX_SELECTIONS, MAX_VOTE_OPTIONS,
 };
 use scrypto::prelude::*;
 
@@ -13,7 +32,25 @@ use scrypto::prelude::*;
     TemperatureCheckVotedEvent,
     ProposalCreatedEvent,
     ProposalVotedEvent,
-    GovernanceParametersUpdatedEvent
+    GovernanceParametersUpdatedEvent,
+    MultisigConfigUpdatedEvent,
+    ActionProposedEvent,
+    ActionApprovedEvent,
+    ActionExecutedEvent,
+    ActionCancelledEvent,
+    PausedEvent,
+    UnpausedEvent,
+    TemperatureCheckCancelledEvent,
+    ProposalCancelledEvent,
+    DeadlineExtendedEvent,
+    TemperatureCheckVoteRetractedEvent,
+    ProposalVoteRetractedEvent,
+    PowerRootPostedEvent,
+    EscrowPositionLockedEvent,
+    EscrowPositionWithdrawnEvent,
+    PowerSourceSetEvent,
+    PowerSourceRemovedEvent,
+    AttestorUpdatedEvent
 )]
 mod governance {
     use super::*;
@@ -27,23 +64,108 @@ mod governance {
             make_temperature_check => PUBLIC;
             vote_on_temperature_check => PUBLIC;
             vote_on_proposal => PUBLIC;
+            vote_on_temperature_check_with_power => PUBLIC;
+            vote_on_proposal_with_power => PUBLIC;
+            vote_on_temperature_check_with_lsus => PUBLIC;
+            vote_on_proposal_with_lsus => PUBLIC;
+            vote_on_temperature_check_with_position => PUBLIC;
+            vote_on_proposal_with_position => PUBLIC;
+            vote_batch => PUBLIC;
+            retract_temperature_check_vote => PUBLIC;
+            retract_proposal_vote => PUBLIC;
+            withdraw_temperature_check => PUBLIC;
             get_governance_parameters => PUBLIC;
             get_temperature_check_count => PUBLIC;
             get_proposal_count => PUBLIC;
+            get_temperature_check_revote_policy => PUBLIC;
+            get_proposal_revote_policy => PUBLIC;
+            get_temperature_check => PUBLIC;
+            get_temperature_checks => PUBLIC;
+            get_proposal => PUBLIC;
+            get_proposals => PUBLIC;
+            get_votes => PUBLIC;
+            get_temperature_check_tally => PUBLIC;
+            get_proposal_tally => PUBLIC;
+            get_temperature_check_result => PUBLIC;
+            get_proposal_result => PUBLIC;
+            get_account_vote => PUBLIC;
+            get_account_vote_history => PUBLIC;
+            get_author_stats => PUBLIC;
+            get_author_items => PUBLIC;
+            get_multisig_config => PUBLIC;
+            get_pending_action => PUBLIC;
+            get_pending_action_count => PUBLIC;
+            get_pause_flags => PUBLIC;
+            lock_escrow_position => PUBLIC;
+            withdraw_escrow_position => PUBLIC;
+            get_escrow_position_resource => PUBLIC;
+            get_escrow_position => PUBLIC;
+            get_escrow_position_power => PUBLIC;
+            get_power_sources => PUBLIC;
+            get_item_power_sources => PUBLIC;
+            get_attestor_badge => PUBLIC;
+            // Attestor methods (authorized by the attestor badge)
+            post_power_root => PUBLIC;
+            // Admin methods (authorized by the admin badge proof passed as argument)
+            propose_action => PUBLIC;
+            approve_action => PUBLIC;
+            execute_action => PUBLIC;
+            cancel_action => PUBLIC;
             // Owner-only methods
             make_proposal => restrict_to: [owner];
             update_governance_parameters => restrict_to: [owner];
             toggle_temperature_check_hidden => restrict_to: [owner];
             toggle_proposal_hidden => restrict_to: [owner];
+            cancel_temperature_check => restrict_to: [owner];
+            cancel_proposal => restrict_to: [owner];
+            extend_temperature_check_deadline => restrict_to: [owner];
+            extend_proposal_deadline => restrict_to: [owner];
+            set_multisig_config => restrict_to: [owner];
+            pause => restrict_to: [owner];
+            unpause => restrict_to: [owner];
+            set_power_source => restrict_to: [owner];
+            remove_power_source => restrict_to: [owner];
+            set_attestor_badge => restrict_to: [owner];
         }
     }
 
     struct Governance {
         pub governance_parameters: GovernanceParameters,
         pub temperature_checks: KeyValueStore<u64, TemperatureCheck>,
+        /// Immutable temperature check content, kept out of the voting path.
+        /// Also the content of the proposal the temperature check is elevated to.
+        pub temperature_check_contents: KeyValueStore<u64, ItemContent>,
         pub temperature_check_count: u64,
         pub proposals: KeyValueStore<u64, Proposal>,
         pub proposal_count: u64,
+        /// M-of-N admin approval settings, None while the subsystem is disabled
+        pub multisig_config: Option<MultisigConfig>,
+        pub pending_actions: KeyValueStore<u64, PendingAction>,
+        pub pending_action_count: u64,
+        /// Emergency pause flags, all false during normal operation
+        pub pause_flags: PauseFlags,
+        /// When the current pause started, None if not paused
+        pub paused_at: Option<Instant>,
+        /// When temperature check voting was paused, None while it is not
+        pub temperature_check_voting_paused_at: Option<Instant>,
+        /// When proposal voting was paused, None while it is not
+        pub proposal_voting_paused_at: Option<Instant>,
+        /// Badge that authorizes `post_power_root`, the owner badge by default
+        pub attestor_badge: ResourceAddress,
+        /// Per-account index of the items each account has voted on
+        pub account_votes: KeyValueStore<Global<Account>, AccountVoteIndex>,
+        /// Per-author index of created temperature checks and author statistics
+        pub authors: KeyValueStore<Global<Account>, AuthorIndex>,
+        /// Non-fungible resource of vote-escrow positions, minted and burned by this component
+        pub escrow_position_resource: NonFungibleResourceManager,
+        pub escrow_positions: KeyValueStore<NonFungibleLocalId, EscrowPosition>,
+        pub escrow_position_count: u64,
+        /// Tokens locked in vote-escrow positions, one vault per locked resource
+        pub escrow_vaults: KeyValueStore<ResourceAddress, Vault>,
+        /// Owner-managed registry of the resources and components that count towards power
+        pub power_sources: Vec<PowerSource>,
+        /// Copy of the registry taken by each item at creation
+        pub power_source_snapshots: KeyValueStore<(ItemKind, u64), Vec<PowerSource>>,
     }
 
     impl Governance {
@@ -52,15 +174,57 @@ mod governance {
             owner_badge: ResourceAddress,
             governance_parameters: GovernanceParameters,
         ) -> Global<Governance> {
+            Self::assert_valid_governance_parameters(&governance_parameters);
+
+            let (address_reservation, component_address) =
+                Runtime::allocate_component_address(Governance::blueprint_id());
+
+            let escrow_position_resource =
+                ResourceBuilder::new_integer_non_fungible::<EscrowPositionData>(OwnerRole::Fixed(
+                    rule!(require(owner_badge)),
+                ))
+                .metadata(metadata! {
+                    init {
+                        "name" => "Governance Vote-Escrow Position", locked;
+                    }
+                })
+                .mint_roles(mint_roles! {
+                    minter => rule!(require(global_caller(component_address)));
+                    minter_updater => rule!(deny_all);
+                })
+                .burn_roles(burn_roles! {
+                    burner => rule!(require(global_caller(component_address)));
+                    burner_updater => rule!(deny_all);
+                })
+                .create_with_no_initial_supply();
+
             Self {
                 governance_parameters,
                 temperature_checks: KeyValueStore::new(),
+                temperature_check_contents: KeyValueStore::new(),
                 temperature_check_count: 0,
                 proposals: KeyValueStore::new(),
                 proposal_count: 0,
+                multisig_config: None,
+                pending_actions: KeyValueStore::new(),
+                pending_action_count: 0,
+                pause_flags: PauseFlags::default(),
+                paused_at: None,
+                temperature_check_voting_paused_at: None,
+                proposal_voting_paused_at: None,
+                attestor_badge: owner_badge,
+                account_votes: KeyValueStore::new(),
+                authors: KeyValueStore::new(),
+                escrow_position_resource,
+                escrow_positions: KeyValueStore::new(),
+                escrow_position_count: 0,
+                escrow_vaults: KeyValueStore::new(),
+                power_sources: Vec::new(),
+                power_source_snapshots: KeyValueStore::new(),
             }
             .instantiate()
             .prepare_to_globalize(OwnerRole::Fixed(rule!(require(owner_badge))))
+            .with_address(address_reservation)
             .roles(roles! {
                 owner => rule!(require(owner_badge));
             })
@@ -70,12 +234,64 @@ mod governance {
                     make_proposal => Free, updatable;
                     vote_on_temperature_check => Free, updatable;
                     vote_on_proposal => Free, updatable;
+                    vote_on_temperature_check_with_power => Free, updatable;
+                    vote_on_proposal_with_power => Free, updatable;
+                    vote_on_temperature_check_with_lsus => Free, updatable;
+                    vote_on_proposal_with_lsus => Free, updatable;
+                    vote_on_temperature_check_with_position => Free, updatable;
+                    vote_on_proposal_with_position => Free, updatable;
+                    vote_batch => Free, updatable;
+                    retract_temperature_check_vote => Free, updatable;
+                    retract_proposal_vote => Free, updatable;
                     get_governance_parameters => Free, updatable;
                     get_temperature_check_count => Free, updatable;
                     get_proposal_count => Free, updatable;
+                    get_temperature_check_revote_policy => Free, updatable;
+                    get_proposal_revote_policy => Free, updatable;
+                    get_temperature_check => Free, updatable;
+                    get_temperature_checks => Free, updatable;
+                    get_proposal => Free, updatable;
+                    get_proposals => Free, updatable;
+                    get_votes => Free, updatable;
+                    get_temperature_check_tally => Free, updatable;
+                    get_proposal_tally => Free, updatable;
+                    get_temperature_check_result => Free, updatable;
+                    get_proposal_result => Free, updatable;
+                    post_power_root => Free, updatable;
+                    get_account_vote => Free, updatable;
+                    get_account_vote_history => Free, updatable;
+                    get_author_stats => Free, updatable;
+                    get_author_items => Free, updatable;
                     update_governance_parameters => Free, updatable;
                     toggle_temperature_check_hidden => Free, updatable;
                     toggle_proposal_hidden => Free, updatable;
+                    set_multisig_config => Free, updatable;
+                    get_multisig_config => Free, updatable;
+                    get_pending_action => Free, updatable;
+                    get_pending_action_count => Free, updatable;
+                    propose_action => Free, updatable;
+                    approve_action => Free, updatable;
+                    execute_action => Free, updatable;
+                    cancel_action => Free, updatable;
+                    get_pause_flags => Free, updatable;
+                    pause => Free, updatable;
+                    unpause => Free, updatable;
+                    lock_escrow_position => Free, updatable;
+                    withdraw_escrow_position => Free, updatable;
+                    get_escrow_position_resource => Free, updatable;
+                    get_escrow_position => Free, updatable;
+                    get_escrow_position_power => Free, updatable;
+                    get_power_sources => Free, updatable;
+                    get_item_power_sources => Free, updatable;
+                    set_power_source => Free, updatable;
+                    remove_power_source => Free, updatable;
+                    set_attestor_badge => Free, updatable;
+                    get_attestor_badge => Free, updatable;
+                    withdraw_temperature_check => Free, updatable;
+                    cancel_temperature_check => Free, updatable;
+                    cancel_proposal => Free, updatable;
+                    extend_temperature_check_deadline => Free, updatable;
+                    extend_proposal_deadline => Free, updatable;
                 }
             })
             .globalize()
@@ -92,22 +308,33 @@ mod governance {
             author: Global<Account>,
             draft: TemperatureCheckDraft,
         ) -> u64 {
+            assert!(!self.pause_flags.creation, "Creation is paused");
+
             // Verify the author account is present in the transaction
             Runtime::assert_access_rule(author.get_owner_role().rule);
 
+            let limits = self.governance_parameters.draft_limits;
+
             // Validate inputs
             assert!(
                 !draft.title.is_empty(),
                 "Temperature check title cannot be empty"
             );
+            assert!(
+                draft.title.len() <= limits.max_title_bytes as usize,
+                "Temperature check title cannot exceed {} bytes",
+                limits.max_title_bytes
+            );
             assert!(
                 !draft.short_description.is_empty(),
                 "Temperature check short description cannot be empty"
             );
             assert!(
-                !draft.description.is_empty(),
-                "Temperature check description cannot be empty"
+                draft.short_description.len() <= limits.max_short_description_bytes as usize,
+                "Temperature check short description cannot exceed {} bytes",
+                limits.max_short_description_bytes
             );
+            self.assert_valid_description(&draft.description);
             assert!(
                 !draft.vote_options.is_empty(),
                 "Temperature check must have at least one vote option"
@@ -122,6 +349,81 @@ mod governance {
                 "Too many links (max {})",
                 MAX_LINKS
             );
+            for link in &draft.links {
+                Self::assert_allowed_link_scheme(link);
+            }
+
+            // Validate the RFC reference and attachments
+            if let Some(rfc_reference) = &draft.rfc_reference {
+                Self::assert_allowed_link_scheme(&rfc_reference.url);
+            }
+            assert!(
+                draft.attachments.len() <= MAX_ATTACHMENTS,
+                "Too many attachments (max {})",
+                MAX_ATTACHMENTS
+            );
+            for attachment in &draft.attachments {
+                assert!(
+                    !attachment.name.is_empty()
+                        && attachment.name.len() <= MAX_ATTACHMENT_NAME_LENGTH,
+                    "Attachment name must be between 1 and {} bytes",
+                    MAX_ATTACHMENT_NAME_LENGTH
+                );
+                assert!(
+                    attachment.media_type.len() <= MAX_MEDIA_TYPE_LENGTH
+                        && attachment.media_type.split('/').count() == 2
+                        && attachment
+                            .media_type
+                            .split('/')
+                            .all(|part| !part.is_empty()),
+                    "Invalid attachment media type: {}",
+                    attachment.media_type
+                );
+                Self::assert_allowed_link_scheme(&attachment.url);
+            }
+
+            // Validate vote option labels
+            let mut labels: Vec<&str> = Vec::with_capacity(draft.vote_options.len());
+            for option in &draft.vote_options {
+                assert!(
+                    !option.label.trim().is_empty(),
+                    "Vote option label cannot be empty"
+                );
+                assert!(
+                    option.label.len() <= limits.max_option_label_bytes as usize,
+                    "Vote option label cannot exceed {} bytes",
+                    limits.max_option_label_bytes
+                );
+                assert!(
+                    !labels.contains(&option.label.as_str()),
+                    "Duplicate vote option label: {}",
+                    option.label
+                );
+                labels.push(option.label.as_str());
+
+                if let Some(description) = &option.description {
+                    assert!(
+                        !description.is_empty()
+                            && description.len() <= limits.max_option_description_bytes as usize,
+                        "Vote option description must be between 1 and {} bytes",
+                        limits.max_option_description_bytes
+                    );
+                }
+                if let Some(color) = &option.color {
+                    Self::assert_valid_color(color);
+                }
+                if let Some(link) = &option.link {
+                    Self::assert_allowed_link_scheme(link);
+                }
+            }
+            let abstain_option_label = self.governance_parameters.abstain_option_label.clone();
+            if let Some(label) = &abstain_option_label {
+                assert!(
+                    !labels.contains(&label.as_str()),
+                    "Vote option label is reserved for the abstain option: {}",
+                    label
+                );
+            }
 
             // Validate max_selections
             if let Some(n) = draft.max_selections {
@@ -137,147 +439,500 @@ mod governance {
                 );
             }
 
+            // Validate min_selections, only meaningful for multiple choice
+            if let Some(n) = draft.min_selections {
+                let max = draft
+                    .max_selections
+                    .expect("min_selections requires max_selections");
+                assert!(n > 0, "min_selections must be greater than 0");
+                assert!(n <= max, "min_selections cannot exceed max_selections");
+            }
+
+            Self::assert_valid_eligibility(&draft.eligibility);
+
             // Auto-generate IDs for vote options (0, 1, 2, ...)
-            let vote_options: Vec<ProposalVoteOption> = draft
+            let mut vote_options: Vec<ProposalVoteOption> = draft
                 .vote_options
                 .into_iter()
                 .enumerate()
                 .map(|(index, input)| ProposalVoteOption {
                     id: ProposalVoteOptionId(index as u32),
                     label: input.label,
+                    description: input.description,
+                    color: input.color,
+                    link: input.link,
+                    is_abstain: false,
                 })
                 .collect();
 
+            // Append the abstain option last, so the draft's option IDs are unaffected
+            let abstain_option_id = abstain_option_label.map(|label| {
+                let id = ProposalVoteOptionId(vote_options.len() as u32);
+                vote_options.push(ProposalVoteOption {
+                    id,
+                    label,
+                    description: None,
+                    color: None,
+                    link: None,
+                    is_abstain: true,
+                });
+                id
+            });
+
+            // Validate the scheduled start, if any
+            let now = Clock::current_time_rounded_to_seconds();
+            let start = match draft.start {
+                None => now,
+                Some(start) => {
+                    assert!(
+                        start.compare(now, TimeComparisonOperator::Gte),
+                        "Start cannot be in the past"
+                    );
+                    let latest_start = now
+                        .add_minutes(self.governance_parameters.max_start_delay_minutes as i64)
+                        .unwrap();
+                    assert!(
+                        start.compare(latest_start, TimeComparisonOperator::Lte),
+                        "Start cannot be more than {} minutes in the future",
+                        self.governance_parameters.max_start_delay_minutes
+                    );
+                    start
+                }
+            };
+
             let id = self.temperature_check_count;
             self.temperature_check_count += 1;
 
-            let now = Clock::current_time_rounded_to_seconds();
-            let deadline = now
-                .add_days(self.governance_parameters.temperature_check_days as i64)
+            let deadline = start
+                .add_minutes(self.governance_parameters.temperature_check_length_minutes as i64)
                 .unwrap();
 
-            let temperature_check = TemperatureCheck {
+            let content = ItemContent {
                 title: draft.title,
                 short_description: draft.short_description,
                 description: draft.description,
                 vote_options,
                 links: draft.links,
+                rfc_reference: draft.rfc_reference,
+                attachments: draft.attachments,
+            };
+
+            let temperature_check = TemperatureCheck {
+                vote_option_count: content.vote_options.len() as u32,
+                abstain_option_id,
+                tally: TemperatureCheckTally::default(),
+                power_mode: self.governance_parameters.power_mode,
+                power_root: None,
+                power_tally: TemperatureCheckPowerTally::default(),
                 quorum: self.governance_parameters.temperature_check_quorum,
                 max_selections: draft.max_selections,
+                min_selections: draft.min_selections,
+                eligibility: draft.eligibility.clone(),
                 voters: KeyValueStore::new(),
+                retracted_voters: KeyValueStore::new(),
+                position_voters: KeyValueStore::new(),
+                eligibility_voters: KeyValueStore::new(),
+                eligibility_amounts: KeyValueStore::new(),
                 votes: KeyValueStore::new(),
                 vote_count: 0,
                 revote_count: 0,
+                retraction_count: 0,
                 approval_threshold: self
                     .governance_parameters
                     .temperature_check_approval_threshold,
-                start: now,
+                start,
                 deadline,
+                created_epoch: Runtime::current_epoch(),
+                created_transaction_hash: Runtime::transaction_hash(),
+                deadline_extension_minutes: 0,
+                anti_sniping: self.governance_parameters.anti_sniping,
+                anti_sniping_extension_minutes: 0,
+                revote_policy: self.governance_parameters.revote_policy,
                 elevated_proposal_id: None,
                 author,
                 hidden: false,
+                cancellation: None,
             };
 
-            let title = temperature_check.title.clone();
+            let title = content.title.clone();
+            let rfc_reference = content.rfc_reference.clone();
+            let attachments = content.attachments.clone();
+            let vote_options = content.vote_options.clone();
             let start = temperature_check.start;
             let deadline = temperature_check.deadline;
+            let created_epoch = temperature_check.created_epoch;
+            let created_transaction_hash = temperature_check.created_transaction_hash;
+            let eligibility = temperature_check.eligibility.clone();
 
             self.temperature_checks.insert(id, temperature_check);
+            self.temperature_check_contents.insert(id, content);
+            let power_sources = self.power_sources.clone();
+            self.power_source_snapshots
+                .insert((ItemKind::TemperatureCheck, id), power_sources.clone());
+            self.index_authored_temperature_check(author, id);
 
             Runtime::emit_event(TemperatureCheckCreatedEvent {
                 temperature_check_id: id,
                 title,
+                rfc_reference,
+                attachments,
+                vote_options,
+                power_sources,
                 start,
                 deadline,
+                created_epoch,
+                created_transaction_hash,
+                eligibility,
             });
 
             id
         }
 
         /// Elevates a temperature check to a proposal (GP - Governance Proposal)
-        /// Only callable by the owner
+        /// Only callable by the owner, while multisig approvals are disabled
         ///
         /// # Arguments
         /// * `temperature_check_id` - The ID of the temperature check to elevate
+        /// * `eligibility` - Holding voters must prove to vote on the proposal, None for all
+        ///   accounts. Pass the temperature check's rule to keep the same electorate.
         ///
         /// Returns the ID of the created proposal
-        pub fn make_proposal(&mut self, temperature_check_id: u64) -> u64 {
-            // Get the temperature check
-            let mut tc = self
-                .temperature_checks
-                .get_mut(&temperature_check_id)
-                .expect("Temperature check not found");
+        pub fn make_proposal(
+            &mut self,
+            temperature_check_id: u64,
+            eligibility: Option<EligibilityRule>,
+        ) -> u64 {
+            self.assert_multisig_disabled();
+            self.make_proposal_internal(temperature_check_id, eligibility)
+        }
 
-            assert!(
-                tc.elevated_proposal_id.is_none(),
-                "Temperature check has already been elevated to a proposal"
+        /// Vote on a temperature check
+        /// The account must prove its presence. On a gated temperature check,
+        /// `eligibility_proof` must show the required badges, which are then bound to the account.
+        pub fn vote_on_temperature_check(
+            &mut self,
+            account: Global<Account>,
+            temperature_check_id: u64,
+            vote: TemperatureCheckVote,
+            rationale: Option<VoteRationale>,
+            eligibility_proof: Option<Proof>,
+        ) {
+            // Verify the account is present in the transaction
+            Runtime::assert_access_rule(account.get_owner_role().rule);
+
+            self.cast_temperature_check_vote(
+                account,
+                temperature_check_id,
+                vote,
+                rationale,
+                PowerClaim::None,
+                eligibility_proof,
             );
+        }
 
-            let proposal_id = self.proposal_count;
-            self.proposal_count += 1;
+        /// Vote on a proposal
+        /// The account must prove its presence
+        ///
+        /// # Arguments
+        /// * `account` - The account casting the vote
+        /// * `proposal_id` - The ID of the proposal to vote on
+        /// * `options` - The selected option(s):
+        ///   - For single-choice proposals: provide exactly one option
+        ///   - For multiple-choice proposals: provide between min_selections (default 1)
+        ///     and max_selections options
+        /// * `rationale` - Optional public explanation of the vote
+        /// * `eligibility_proof` - On a gated proposal, proof of the required badges, which are
+        ///   then bound to the account. None for ungated proposals.
+        pub fn vote_on_proposal(
+            &mut self,
+            account: Global<Account>,
+            proposal_id: u64,
+            options: Vec<ProposalVoteOptionId>,
+            rationale: Option<VoteRationale>,
+            eligibility_proof: Option<Proof>,
+        ) {
+            // Verify the account is present in the transaction
+            Runtime::assert_access_rule(account.get_owner_role().rule);
 
-            let now = Clock::current_time_rounded_to_seconds();
-            let deadline = now
-                .add_days(self.governance_parameters.proposal_length_days as i64)
-                .unwrap();
+            self.cast_proposal_vote(
+                account,
+                proposal_id,
+                options,
+                rationale,
+                PowerClaim::None,
+                eligibility_proof,
+            );
+        }
 
-            let proposal = Proposal {
-                title: tc.title.clone(),
-                short_description: tc.short_description.clone(),
-                description: tc.description.clone(),
-                vote_options: tc.vote_options.clone(),
-                links: tc.links.clone(),
-                quorum: self.governance_parameters.proposal_quorum,
-                max_selections: tc.max_selections,
-                voters: KeyValueStore::new(),
-                votes: KeyValueStore::new(),
-                vote_count: 0,
-                revote_count: 0,
-                approval_threshold: self.governance_parameters.proposal_approval_threshold,
-                start: now,
-                deadline,
+        /// Vote on a temperature check in `MerkleSnapshot` power mode
+        /// The account must prove its presence, and `power_proof` must show its power
+        /// in the snapshot posted by the attestor
+        pub fn vote_on_temperature_check_with_power(
+            &mut self,
+            account: Global<Account>,
+            temperature_check_id: u64,
+            vote: TemperatureCheckVote,
+            rationale: Option<VoteRationale>,
+            power_proof: MerklePowerProof,
+            eligibility_proof: Option<Proof>,
+        ) {
+            // Verify the account is present in the transaction
+            Runtime::assert_access_rule(account.get_owner_role().rule);
+
+            self.cast_temperature_check_vote(
+                account,
                 temperature_check_id,
-                author: tc.author,
-                hidden: false,
-            };
+                vote,
+                rationale,
+                PowerClaim::Merkle(power_proof),
+                eligibility_proof,
+            );
+        }
 
-            tc.elevated_proposal_id = Some(proposal_id);
-            drop(tc);
+        /// Vote on a proposal in `MerkleSnapshot` power mode
+        /// The account must prove its presence, and `power_proof` must show its power
+        /// in the snapshot posted by the attestor
+        pub fn vote_on_proposal_with_power(
+            &mut self,
+            account: Global<Account>,
+            proposal_id: u64,
+            options: Vec<ProposalVoteOptionId>,
+            rationale: Option<VoteRationale>,
+            power_proof: MerklePowerProof,
+            eligibility_proof: Option<Proof>,
+        ) {
+            // Verify the account is present in the transaction
+            Runtime::assert_access_rule(account.get_owner_role().rule);
 
-            let title = proposal.title.clone();
-            let start = proposal.start;
-            let deadline = proposal.deadline;
+            self.cast_proposal_vote(
+                account,
+                proposal_id,
+                options,
+                rationale,
+                PowerClaim::Merkle(power_proof),
+                eligibility_proof,
+            );
+        }
 
-            self.proposals.insert(proposal_id, proposal);
+        /// Vote on a temperature check in `LsuProofs` power mode
+        /// The account must prove its presence. The voting power is the XRD value of the
+        /// presented validator stake units at the time of the vote.
+        pub fn vote_on_temperature_check_with_lsus(
+            &mut self,
+            account: Global<Account>,
+            temperature_check_id: u64,
+            vote: TemperatureCheckVote,
+            rationale: Option<VoteRationale>,
+            lsu_proofs: Vec<Proof>,
+            eligibility_proof: Option<Proof>,
+        ) {
+            // Verify the account is present in the transaction
+            Runtime::assert_access_rule(account.get_owner_role().rule);
 
-            Runtime::emit_event(ProposalCreatedEvent {
-                proposal_id,
+            self.cast_temperature_check_vote(
+                account,
                 temperature_check_id,
-                title,
-                start,
-                deadline,
-            });
+                vote,
+                rationale,
+                PowerClaim::Lsu(lsu_proofs),
+                eligibility_proof,
+            );
+        }
 
-            proposal_id
+        /// Vote on a proposal in `LsuProofs` power mode
+        /// The account must prove its presence. The voting power is the XRD value of the
+        /// presented validator stake units at the time of the vote.
+        pub fn vote_on_proposal_with_lsus(
+            &mut self,
+            account: Global<Account>,
+            proposal_id: u64,
+            options: Vec<ProposalVoteOptionId>,
+            rationale: Option<VoteRationale>,
+            lsu_proofs: Vec<Proof>,
+            eligibility_proof: Option<Proof>,
+        ) {
+            // Verify the account is present in the transaction
+            Runtime::assert_access_rule(account.get_owner_role().rule);
+
+            self.cast_proposal_vote(
+                account,
+                proposal_id,
+                options,
+                rationale,
+                PowerClaim::Lsu(lsu_proofs),
+                eligibility_proof,
+            );
         }
 
-        /// Vote on a temperature check
-        /// The account must prove its presence
-        pub fn vote_on_temperature_check(
+        /// Vote on a temperature check in `VoteEscrow` power mode
+        /// The account must prove its presence, and `position_proofs` must show one or more
+        /// vote-escrow positions, whose powers are summed. The positions back only this
+        /// account's ballot on the temperature check. Recasting replaces the ballot's power.
+        pub fn vote_on_temperature_check_with_position(
             &mut self,
             account: Global<Account>,
             temperature_check_id: u64,
             vote: TemperatureCheckVote,
+            rationale: Option<VoteRationale>,
+            position_proofs: Vec<Proof>,
+            eligibility_proof: Option<Proof>,
         ) {
             // Verify the account is present in the transaction
             Runtime::assert_access_rule(account.get_owner_role().rule);
 
-            // Get the temperature check
+            let (position_ids, power) = self.check_escrow_position_proofs(position_proofs);
+            self.cast_temperature_check_vote(
+                account,
+                temperature_check_id,
+                vote,
+                rationale,
+                PowerClaim::Position {
+                    position_ids: position_ids.clone(),
+                    power,
+                },
+                eligibility_proof,
+            );
+            for position_id in &position_ids {
+                self.record_escrow_vote(
+                    position_id,
+                    ItemKind::TemperatureCheck,
+                    temperature_check_id,
+                );
+            }
+        }
+
+        /// Vote on a proposal in `VoteEscrow` power mode
+        /// The account must prove its presence, and `position_proofs` must show one or more
+        /// vote-escrow positions, whose powers are summed. The positions back only this
+        /// account's ballot on the proposal. Recasting replaces the ballot's power.
+        pub fn vote_on_proposal_with_position(
+            &mut self,
+            account: Global<Account>,
+            proposal_id: u64,
+            options: Vec<ProposalVoteOptionId>,
+            rationale: Option<VoteRationale>,
+            position_proofs: Vec<Proof>,
+            eligibility_proof: Option<Proof>,
+        ) {
+            // Verify the account is present in the transaction
+            Runtime::assert_access_rule(account.get_owner_role().rule);
+
+            let (position_ids, power) = self.check_escrow_position_proofs(position_proofs);
+            self.cast_proposal_vote(
+                account,
+                proposal_id,
+                options,
+                rationale,
+                PowerClaim::Position {
+                    position_ids: position_ids.clone(),
+                    power,
+                },
+                eligibility_proof,
+            );
+            for position_id in &position_ids {
+                self.record_escrow_vote(position_id, ItemKind::Proposal, proposal_id);
+            }
+        }
+
+        /// Casts several ballots in one call, across accounts and items
+        /// Each distinct account must prove its presence once. The ballots are applied
+        /// in order and atomically: if any ballot is invalid, none of them are recorded.
+        /// Each ballot emits its normal voted event. Gated items need an eligibility proof
+        /// and cannot be voted on in a batch.
+        pub fn vote_batch(&mut self, ballots: Vec<BallotInput>) {
+            assert!(
+                !ballots.is_empty(),
+                "Batch must contain at least one ballot"
+            );
+            assert!(
+                ballots.len() <= MAX_BATCH_BALLOTS,
+                "Too many ballots in batch (max {})",
+                MAX_BATCH_BALLOTS
+            );
+
+            // Validate the batch as a whole before recording anything
+            let mut seen_ballots: Vec<(Global<Account>, ItemKind, u64)> = Vec::new();
+            let mut verified_accounts: Vec<Global<Account>> = Vec::new();
+            for ballot in &ballots {
+                let key = (ballot.account, ballot.vote.item_kind(), ballot.item_id);
+                assert!(
+                    !seen_ballots.contains(&key),
+                    "Duplicate ballot for the same account and item"
+                );
+                seen_ballots.push(key);
+
+                // Ballots carry no eligibility proof
+                let gated = match ballot.vote {
+                    BallotVote::TemperatureCheck(_) => self
+                        .temperature_checks
+                        .get(&ballot.item_id)
+                        .map(|tc| tc.eligibility.is_some()),
+                    BallotVote::Proposal(_) => self
+                        .proposals
+                        .get(&ballot.item_id)
+                        .map(|proposal| proposal.eligibility.is_some()),
+                };
+                assert!(
+                    gated != Some(true),
+                    "Gated items cannot be voted on in a batch"
+                );
+
+                // Verify each account is present in the transaction, once
+                if !verified_accounts.contains(&ballot.account) {
+                    Runtime::assert_access_rule(ballot.account.get_owner_role().rule);
+                    verified_accounts.push(ballot.account);
+                }
+            }
+
+            for ballot in ballots {
+                match ballot.vote {
+                    BallotVote::TemperatureCheck(vote) => self.cast_temperature_check_vote(
+                        ballot.account,
+                        ballot.item_id,
+                        vote,
+                        ballot.rationale,
+                        PowerClaim::None,
+                        None,
+                    ),
+                    BallotVote::Proposal(options) => self.cast_proposal_vote(
+                        ballot.account,
+                        ballot.item_id,
+                        options,
+                        ballot.rationale,
+                        PowerClaim::None,
+                        None,
+                    ),
+                }
+            }
+        }
+
+        /// Retracts the account's vote on a temperature check
+        /// Removes the account from the voters and appends a retraction record to the votes.
+        /// A retraction counts as a change of vote under the revote policy.
+        /// Retractions don't trigger anti-sniping, the deadline stays where it is.
+        pub fn retract_temperature_check_vote(
+            &mut self,
+            account: Global<Account>,
+            temperature_check_id: u64,
+        ) {
+            assert!(
+                !self.pause_flags.temperature_check_voting,
+                "Temperature check voting is paused"
+            );
+
+            // Verify the account is present in the transaction
+            Runtime::assert_access_rule(account.get_owner_role().rule);
+
             let mut tc = self
                 .temperature_checks
                 .get_mut(&temperature_check_id)
                 .expect("Temperature check not found");
 
+            assert!(
+                tc.cancellation.is_none(),
+                "Temperature check has been cancelled"
+            );
+
             // Check the vote is still open
             let now = Clock::current_time_rounded_to_seconds();
             assert!(
@@ -289,64 +944,73 @@ mod governance {
                 "Voting has ended"
             );
 
-            // Check if the account has already voted (revote scenario)
-            let old_vote_id = tc.voters.get(&account).map(|e| e.vote_id);
-            let replacing_vote_id = if let Some(id) = old_vote_id {
-                tc.revote_count += 1;
-                Some(id)
-            } else {
-                None
-            };
+            let entry = tc
+                .voters
+                .remove(&account)
+                .expect("Account has not voted on this temperature check");
+            Self::assert_revote_allowed(tc.revote_policy, entry.revotes, tc.deadline, now);
 
-            // Get the vote ID and increment the counter
             let vote_id = tc.vote_count;
             tc.vote_count += 1;
+            tc.retraction_count += 1;
+            tc.tally.remove(entry.vote);
+            tc.power_tally.remove(entry.vote, entry.power);
 
-            // Record the vote in both stores (insert replaces existing entry for the account)
-            tc.voters
-                .insert(account, TemperatureCheckVoterEntry { vote_id, vote });
+            tc.retracted_voters.insert(account, entry.revotes + 1);
             tc.votes.insert(
                 vote_id,
                 TemperatureCheckVoteRecord {
                     voter: account,
-                    vote,
-                    replacing_vote_id,
+                    vote: None,
+                    replacing_vote_id: Some(entry.vote_id),
+                    rationale: None,
+                    power: entry.power,
+                    position_ids: entry.position_ids,
                 },
             );
 
-            Runtime::emit_event(TemperatureCheckVotedEvent {
+            Runtime::emit_event(TemperatureCheckVoteRetractedEvent {
                 temperature_check_id,
                 vote_id,
                 account,
-                vote,
-                replacing_vote_id,
+                retracted_vote_id: entry.vote_id,
             });
-        }
 
-        /// Vote on a proposal
-        /// The account must prove its presence
-        ///
-        /// # Arguments
-        /// * `account` - The account casting the vote
-        /// * `proposal_id` - The ID of the proposal to vote on
-        /// * `options` - The selected option(s):
-        ///   - For single-choice proposals: provide exactly one option
-        ///   - For multiple-choice proposals: provide up to max_selections options
-        pub fn vote_on_proposal(
-            &mut self,
-            account: Global<Account>,
-            proposal_id: u64,
-            options: Vec<ProposalVoteOptionId>,
-        ) {
+            Self::index_account_vote(
+                &mut self.account_votes,
+                account,
+                AccountVote {
+                    item_kind: ItemKind::TemperatureCheck,
+                    item_id: temperature_check_id,
+                    vote_id,
+                    retracted: true,
+                },
+            );
+        }
+
+        /// Retracts the account's vote on a proposal
+        /// Removes the account from the voters and appends a retraction record to the votes.
+        /// A retraction counts as a change of vote under the revote policy.
+        /// Retractions don't trigger anti-sniping, the deadline stays where it is.
+        pub fn retract_proposal_vote(&mut self, account: Global<Account>, proposal_id: u64) {
+            assert!(
+                !self.pause_flags.proposal_voting,
+                "Proposal voting is paused"
+            );
+
             // Verify the account is present in the transaction
             Runtime::assert_access_rule(account.get_owner_role().rule);
 
-            // Get the proposal
             let mut proposal = self
                 .proposals
                 .get_mut(&proposal_id)
                 .expect("Proposal not found");
 
+            assert!(
+                proposal.cancellation.is_none(),
+                "Proposal has been cancelled"
+            );
+
             // Check the vote is still open
             let now = Clock::current_time_rounded_to_seconds();
             assert!(
@@ -358,79 +1022,56 @@ mod governance {
                 "Voting has ended"
             );
 
-            // Validate option count based on max_selections
-            assert!(!options.is_empty(), "Must select at least one option");
-
-            match proposal.max_selections {
-                None => {
-                    // Single choice: exactly one option
-                    assert!(
-                        options.len() == 1,
-                        "This is a single-choice proposal, select exactly one option"
-                    );
-                }
-                Some(max) => {
-                    // Multiple choice: up to max options
-                    assert!(
-                        options.len() <= max as usize,
-                        "Cannot select more than {} options",
-                        max
-                    );
-                }
-            }
-
-            // Check for duplicate selections
-            let mut seen = Vec::new();
-            for option in &options {
-                assert!(!seen.contains(option), "Duplicate vote option selected");
-                seen.push(*option);
-            }
-
-            // Validate all selected options exist
-            for option in &options {
-                assert!(
-                    proposal.vote_options.iter().any(|opt| opt.id == *option),
-                    "Invalid vote option"
-                );
-            }
-
-            // Check if the account has already voted (revote scenario)
-            let old_vote_id = proposal.voters.get(&account).map(|e| e.vote_id);
-            let replacing_vote_id = if let Some(id) = old_vote_id {
-                proposal.revote_count += 1;
-                Some(id)
-            } else {
-                None
-            };
+            let entry = proposal
+                .voters
+                .remove(&account)
+                .expect("Account has not voted on this proposal");
+            Self::assert_revote_allowed(
+                proposal.revote_policy,
+                entry.revotes,
+                proposal.deadline,
+                now,
+            );
 
-            // Get the vote ID and increment the counter
             let vote_id = proposal.vote_count;
             proposal.vote_count += 1;
+            proposal.retraction_count += 1;
+            for option in &entry.options {
+                proposal.option_tallies[option.0 as usize] -= 1;
+                proposal.option_powers[option.0 as usize] -= entry.power;
+            }
+            proposal.total_power -= entry.power;
 
-            // Record the vote in both stores (insert replaces existing entry for the account)
-            proposal.voters.insert(
-                account,
-                ProposalVoterEntry {
-                    vote_id,
-                    options: options.clone(),
-                },
-            );
+            proposal.retracted_voters.insert(account, entry.revotes + 1);
             proposal.votes.insert(
                 vote_id,
                 ProposalVoteRecord {
                     voter: account,
-                    options: options.clone(),
-                    replacing_vote_id,
+                    options: None,
+                    replacing_vote_id: Some(entry.vote_id),
+                    rationale: None,
+                    power: entry.power,
+                    position_ids: entry.position_ids,
                 },
             );
 
-            Runtime::emit_event(ProposalVotedEvent {
+            Runtime::emit_event(ProposalVoteRetractedEvent {
                 proposal_id,
                 vote_id,
                 account,
-                options,
-                replacing_vote_id,
+                retracted_vote_id: entry.vote_id,
             });
+
+            Self::index_account_vote(
+                &mut self.account_votes,
+                account,
+                AccountVote {
+                    item_kind: ItemKind::Proposal,
+                    item_id: proposal_id,
+                    vote_id,
+                    retracted: true,
+                },
+            );
         }
 
         /// Returns the current governance parameters
@@ -448,31 +1089,2512 @@ mod governance {
             self.proposal_count
         }
 
-        /// Updates the governance parameters (owner only)
-        pub fn update_governance_parameters(&mut self, new_params: GovernanceParameters) {
-            self.governance_parameters = new_params.clone();
+        /// Returns the revote policy of a temperature check
+        pub fn get_temperature_check_revote_policy(
+            &self,
+            temperature_check_id: u64,
+        ) -> RevotePolicy {
+            self.temperature_checks
+                .get(&temperature_check_id)
+                .expect("Temperature check not found")
+                .revote_policy
+        }
 
-            Runtime::emit_event(GovernanceParametersUpdatedEvent { new_params });
+        /// Returns the revote policy of a proposal
+        pub fn get_proposal_revote_policy(&self, proposal_id: u64) -> RevotePolicy {
+            self.proposals
+                .get(&proposal_id)
+                .expect("Proposal not found")
+                .revote_policy
         }
 
-        /// Toggles the hidden flag on a temperature check (owner only)
-        pub fn toggle_temperature_check_hidden(&mut self, temperature_check_id: u64) {
-            let mut tc = self
+        /// Returns a temperature check with its content, or None if it does not exist
+        pub fn get_temperature_check(
+            &self,
+            temperature_check_id: u64,
+        ) -> Option<TemperatureCheckView> {
+            let tc = self.temperature_checks.get(&temperature_check_id)?;
+            let content = self
+                .temperature_check_contents
+                .get(&temperature_check_id)
+                .unwrap()
+                .clone();
+
+            Some(TemperatureCheckView {
+                id: temperature_check_id,
+                content,
+                quorum: tc.quorum,
+                max_selections: tc.max_selections,
+                min_selections: tc.min_selections,
+                eligibility: tc.eligibility.clone(),
+                abstain_option_id: tc.abstain_option_id,
+                tally: tc.tally,
+                power_mode: tc.power_mode,
+                power_root: tc.power_root,
+                power_tally: tc.power_tally,
+                power_sources: self
+                    .get_item_power_sources(ItemKind::TemperatureCheck, temperature_check_id),
+                vote_count: tc.vote_count,
+                revote_count: tc.revote_count,
+                retraction_count: tc.retraction_count,
+                approval_threshold: tc.approval_threshold,
+                start: tc.start,
+                deadline: tc.deadline,
+                created_epoch: tc.created_epoch,
+                created_transaction_hash: tc.created_transaction_hash,
+                deadline_extension_minutes: tc.deadline_extension_minutes,
+                anti_sniping: tc.anti_sniping,
+                anti_sniping_extension_minutes: tc.anti_sniping_extension_minutes,
+                revote_policy: tc.revote_policy,
+                elevated_proposal_id: tc.elevated_proposal_id,
+                author: tc.author,
+                hidden: tc.hidden,
+                cancellation: tc.cancellation.clone(),
+            })
+        }
+
+        /// Returns up to `limit` temperature checks, starting at ID `from_id`
+        pub fn get_temperature_checks(
+            &self,
+            from_id: u64,
+            limit: u32,
+        ) -> Vec<TemperatureCheckView> {
+            let end = Self::page_end(from_id, limit, self.temperature_check_count);
+            (from_id..end)
+                .filter_map(|id| self.get_temperature_check(id))
+                .collect()
+        }
+
+        /// Returns a proposal with its content, or None if it does not exist
+        pub fn get_proposal(&self, proposal_id: u64) -> Option<ProposalView> {
+            let proposal = self.proposals.get(&proposal_id)?;
+            let content = self
+                .temperature_check_contents
+                .get(&proposal.temperature_check_id)
+                .unwrap()
+                .clone();
+
+            Some(ProposalView {
+                id: proposal_id,
+                content,
+                quorum: proposal.quorum,
+                max_selections: proposal.max_selections,
+                min_selections: proposal.min_selections,
+                eligibility: proposal.eligibility.clone(),
+                abstain_option_id: proposal.abstain_option_id,
+                option_tallies: proposal.option_tallies.clone(),
+                power_mode: proposal.power_mode,
+                power_root: proposal.power_root,
+                option_powers: proposal.option_powers.clone(),
+                power_sources: self.get_item_power_sources(ItemKind::Proposal, proposal_id),
+                vote_count: proposal.vote_count,
+                revote_count: proposal.revote_count,
+                retraction_count: proposal.retraction_count,
+                approval_threshold: proposal.approval_threshold,
+                start: proposal.start,
+                deadline: proposal.deadline,
+                created_epoch: proposal.created_epoch,
+                created_transaction_hash: proposal.created_transaction_hash,
+                deadline_extension_minutes: proposal.deadline_extension_minutes,
+                anti_sniping: proposal.anti_sniping,
+                anti_sniping_extension_minutes: proposal.anti_sniping_extension_minutes,
+                revote_policy: proposal.revote_policy,
+                temperature_check_id: proposal.temperature_check_id,
+                author: proposal.author,
+                hidden: proposal.hidden,
+                cancellation: proposal.cancellation.clone(),
+            })
+        }
+
+        /// Returns up to `limit` proposals, starting at ID `from_id`
+        pub fn get_proposals(&self, from_id: u64, limit: u32) -> Vec<ProposalView> {
+            let end = Self::page_end(from_id, limit, self.proposal_count);
+            (from_id..end)
+                .filter_map(|id| self.get_proposal(id))
+                .collect()
+        }
+
+        /// Returns up to `limit` vote records of an item, starting at vote ID `from_vote_id`
+        /// Retractions are included, as records without a vote
+        pub fn get_votes(
+            &self,
+            item_kind: ItemKind,
+            item_id: u64,
+            from_vote_id: u64,
+            limit: u32,
+        ) -> Vec<VoteView> {
+            match item_kind {
+                ItemKind::TemperatureCheck => {
+                    let tc = self
+                        .temperature_checks
+                        .get(&item_id)
+                        .expect("Temperature check not found");
+                    let end = Self::page_end(from_vote_id, limit, tc.vote_count);
+                    (from_vote_id..end)
+                        .map(|vote_id| VoteView {
+                            vote_id,
+                            record: VoteRecord::TemperatureCheck(
+                                tc.votes.get(&vote_id).unwrap().clone(),
+                            ),
+                        })
+                        .collect()
+                }
+                ItemKind::Proposal => {
+                    let proposal = self.proposals.get(&item_id).expect("Proposal not found");
+                    let end = Self::page_end(from_vote_id, limit, proposal.vote_count);
+                    (from_vote_id..end)
+                        .map(|vote_id| VoteView {
+                            vote_id,
+                            record: VoteRecord::Proposal(
+                                proposal.votes.get(&vote_id).unwrap().clone(),
+                            ),
+                        })
+                        .collect()
+                }
+            }
+        }
+
+        /// Returns the number of accounts currently voting for and against a temperature check
+        pub fn get_temperature_check_tally(
+            &self,
+            temperature_check_id: u64,
+        ) -> TemperatureCheckTally {
+            self.temperature_checks
+                .get(&temperature_check_id)
+                .expect("Temperature check not found")
+                .tally
+        }
+
+        /// Returns the number of accounts currently selecting each option of a proposal,
+        /// indexed by option ID
+        pub fn get_proposal_tally(&self, proposal_id: u64) -> Vec<u64> {
+            self.proposals
+                .get(&proposal_id)
+                .expect("Proposal not found")
+                .option_tallies
+                .clone()
+        }
+
+        /// Evaluates quorum and approval of a weighted temperature check from its power tally
+        pub fn get_temperature_check_result(
+            &self,
+            temperature_check_id: u64,
+        ) -> TemperatureCheckResult {
+            let tc = self
                 .temperature_checks
-                .get_mut(&temperature_check_id)
+                .get(&temperature_check_id)
                 .expect("Temperature check not found");
+            assert!(
+                tc.power_mode != PowerMode::Unweighted,
+                "Results of unweighted items are computed off-ledger"
+            );
 
-            tc.hidden = !tc.hidden;
+            let power_tally = tc.power_tally;
+            let total_power = power_tally.for_power + power_tally.against_power;
+            let quorum_reached = total_power >= tc.quorum;
+            let approved = quorum_reached
+                && total_power > Decimal::ZERO
+                && power_tally.for_power >= total_power * tc.approval_threshold;
+
+            TemperatureCheckResult {
+                power_tally,
+                total_power,
+                quorum_reached,
+                approved,
+            }
         }
 
-        /// Toggles the hidden flag on a proposal (owner only)
-        pub fn toggle_proposal_hidden(&mut self, proposal_id: u64) {
-            let mut proposal = self
+        /// Evaluates quorum and approval of a weighted proposal from its option powers
+        pub fn get_proposal_result(&self, proposal_id: u64) -> ProposalResult {
+            let proposal = self
                 .proposals
-                .get_mut(&proposal_id)
+                .get(&proposal_id)
                 .expect("Proposal not found");
+            assert!(
+                proposal.power_mode != PowerMode::Unweighted,
+                "Results of unweighted items are computed off-ledger"
+            );
 
-            proposal.hidden = !proposal.hidden;
+            // Ballots may select several options, so the total counts each ballot once
+            let total_power = proposal.total_power;
+            let quorum_reached = total_power >= proposal.quorum;
+
+            let abstain_power = proposal
+                .abstain_option_id
+                .map(|id| proposal.option_powers[id.0 as usize])
+                .unwrap_or(Decimal::ZERO);
+            let leading_option = proposal
+                .option_powers
+                .iter()
+                .enumerate()
+                .filter(|(index, power)| {
+                    Some(ProposalVoteOptionId(*index as u32)) != proposal.abstain_option_id
+                        && **power > Decimal::ZERO
+                })
+                .fold(
+                    None,
+                    |leading: Option<(usize, Decimal)>, (index, power)| match leading {
+                        Some((_, leading_power)) if leading_power >= *power => leading,
+                        _ => Some((index, *power)),
+                    },
+                );
+            let approved = match leading_option {
+                Some((_, leading_power)) => {
+                    quorum_reached
+                        && leading_power
+                            >= (total_power - abstain_power) * proposal.approval_threshold
+                }
+                None => false,
+            };
+
+            ProposalResult {
+                option_powers: proposal.option_powers.clone(),
+                total_power,
+                quorum_reached,
+                leading_option: leading_option.map(|(index, _)| ProposalVoteOptionId(index as u32)),
+                approved,
+            }
+        }
+
+        /// Returns the latest vote of an account on an item, or None if it never voted on it
+        pub fn get_account_vote(
+            &self,
+            item_kind: ItemKind,
+            item_id: u64,
+            account: Global<Account>,
+        ) -> Option<AccountVote> {
+            let index = self.account_votes.get(&account)?;
+            let position = *index.positions.get(&(item_kind, item_id))?;
+            let vote = index.history.get(&position).unwrap().clone();
+            Some(vote)
+        }
+
+        /// Returns the track record of an author, all zero if they never created a temperature check
+        pub fn get_author_stats(&self, author: Global<Account>) -> AuthorStats {
+            self.authors
+                .get(&author)
+                .map(|index| index.stats)
+                .unwrap_or_default()
+        }
+
+        /// Returns up to `limit` of an author's temperature checks in creation order,
+        /// starting at position `from`, each with the proposal it was elevated to
+        pub fn get_author_items(
+            &self,
+            author: Global<Account>,
+            from: u64,
+            limit: u32,
+        ) -> Vec<AuthorItem> {
+            let Some(index) = self.authors.get(&author) else {
+                return Vec::new();
+            };
+            let end = Self::page_end(from, limit, index.stats.created);
+            (from..end)
+                .map(|position| {
+                    let temperature_check_id = *index.temperature_checks.get(&position).unwrap();
+                    let proposal_id = self
+                        .temperature_checks
+                        .get(&temperature_check_id)
+                        .unwrap()
+                        .elevated_proposal_id;
+                    AuthorItem {
+                        temperature_check_id,
+                        proposal_id,
+                    }
+                })
+                .collect()
+        }
+
+        /// Returns up to `limit` entries of an account's vote history, starting at position `from`
+        /// Entries are ordered by the account's first vote on each item
+        pub fn get_account_vote_history(
+            &self,
+            account: Global<Account>,
+            from: u64,
+            limit: u32,
+        ) -> Vec<AccountVote> {
+            let Some(index) = self.account_votes.get(&account) else {
+                return Vec::new();
+            };
+            let end = Self::page_end(from, limit, index.count);
+            (from..end)
+                .map(|position| index.history.get(&position).unwrap().clone())
+                .collect()
+        }
+
+        /// Posts the Merkle root of the (account, power) snapshot of an item (attestor only)
+        /// Only for items in `MerkleSnapshot` power mode, once, and before any vote is cast
+        pub fn post_power_root(&mut self, item_kind: ItemKind, item_id: u64, root: Hash) {
+            Runtime::assert_access_rule(rule!(require(self.attestor_badge)));
+
+            match item_kind {
+                ItemKind::TemperatureCheck => {
+                    let mut tc = self
+                        .temperature_checks
+                        .get_mut(&item_id)
+                        .expect("Temperature check not found");
+                    Self::assert_power_root_can_be_posted(
+                        tc.power_mode,
+                        tc.power_root,
+                        tc.vote_count,
+                    );
+                    tc.power_root = Some(root);
+                }
+                ItemKind::Proposal => {
+                    let mut proposal = self
+                        .proposals
+                        .get_mut(&item_id)
+                        .expect("Proposal not found");
+                    Self::assert_power_root_can_be_posted(
+                        proposal.power_mode,
+                        proposal.power_root,
+                        proposal.vote_count,
+                    );
+                    proposal.power_root = Some(root);
+                }
+            }
+
+            Runtime::emit_event(PowerRootPostedEvent {
+                item_kind,
+                item_id,
+                root,
+            });
+        }
+
+        /// Locks XRD or validator stake units (LSUs) into a new vote-escrow position
+        /// Returns the position non-fungible. Its voting power is the XRD value of the locked
+        /// tokens, scaled by the remaining lock time over `MAX_ESCROW_LOCK_DAYS`.
+        ///
+        /// # Arguments
+        /// * `tokens` - XRD or the LSUs of a genuine validator
+        /// * `lock_days` - Days until the position can be withdrawn (1 to `MAX_ESCROW_LOCK_DAYS`)
+        pub fn lock_escrow_position(&mut self, tokens: Bucket, lock_days: u32) -> Bucket {
+            let locked_resource = tokens.resource_address();
+            if locked_resource != XRD {
+                Self::lsu_validator(locked_resource);
+            }
+            let amount = tokens.amount();
+            assert!(amount > Decimal::ZERO, "Cannot lock an empty bucket");
+            assert!(
+                (1..=MAX_ESCROW_LOCK_DAYS).contains(&lock_days),
+                "Lock duration must be between 1 and {} days",
+                MAX_ESCROW_LOCK_DAYS
+            );
+
+            let now = Clock::current_time_rounded_to_seconds();
+            let unlock_at = now.add_minutes(lock_days as i64 * 24 * 60).unwrap();
+
+            if self.escrow_vaults.get(&locked_resource).is_some() {
+                self.escrow_vaults
+                    .get_mut(&locked_resource)
+                    .unwrap()
+                    .put(tokens);
+            } else {
+                self.escrow_vaults
+                    .insert(locked_resource, Vault::with_bucket(tokens));
+            }
+
+            let position_id = NonFungibleLocalId::integer(self.escrow_position_count);
+            self.escrow_position_count += 1;
+            self.escrow_positions.insert(
+                position_id.clone(),
+                EscrowPosition {
+                    locked_resource,
+                    amount,
+                    locked_at: now,
+                    unlock_at,
+                    voted_items: Vec::new(),
+                },
+            );
+
+            let position = self.escrow_position_resource.mint_non_fungible(
+                &position_id,
+                EscrowPositionData {
+                    locked_resource,
+                    amount,
+                    unlock_at,
+                },
+            );
+
+            Runtime::emit_event(EscrowPositionLockedEvent {
+                position_id,
+                locked_resource,
+                amount,
+                unlock_at,
+            });
+
+            position.into()
+        }
+
+        /// Burns a vote-escrow position and returns its locked tokens
+        /// The lock must have expired, and every item the position voted on must have closed
+        pub fn withdraw_escrow_position(&mut self, position: NonFungibleBucket) -> Bucket {
+            assert!(
+                position.resource_address() == self.escrow_position_resource.address(),
+                "Invalid vote-escrow position"
+            );
+            let position_id = position.non_fungible_local_id();
+            let escrow = self
+                .escrow_positions
+                .get(&position_id)
+                .expect("Vote-escrow position not found")
+                .clone();
+
+            let now = Clock::current_time_rounded_to_seconds();
+            assert!(
+                now.compare(escrow.unlock_at, TimeComparisonOperator::Gte),
+                "Vote-escrow position is still locked"
+            );
+            assert!(
+                escrow
+                    .voted_items
+                    .iter()
+                    .all(|(item_kind, item_id)| !self.is_item_open(*item_kind, *item_id, now)),
+                "Vote-escrow position has voted on an item that is still open"
+            );
+
+            self.escrow_positions.remove(&position_id);
+            position.burn();
+            let tokens = self
+                .escrow_vaults
+                .get_mut(&escrow.locked_resource)
+                .unwrap()
+                .take(escrow.amount);
+
+            Runtime::emit_event(EscrowPositionWithdrawnEvent {
+                position_id,
+                locked_resource: escrow.locked_resource,
+                amount: escrow.amount,
+            });
+
+            tokens
+        }
+
+        /// Returns the address of the vote-escrow position resource
+        pub fn get_escrow_position_resource(&self) -> ResourceAddress {
+            self.escrow_position_resource.address()
+        }
+
+        /// Returns the state of a vote-escrow position, or None if it does not exist
+        pub fn get_escrow_position(
+            &self,
+            position_id: NonFungibleLocalId,
+        ) -> Option<EscrowPosition> {
+            self.escrow_positions
+                .get(&position_id)
+                .map(|position| position.clone())
+        }
+
+        /// Returns the voting power a vote-escrow position would have in a ballot cast now
+        /// Zero once the lock has expired
+        pub fn get_escrow_position_power(&self, position_id: NonFungibleLocalId) -> Decimal {
+            let position = self
+                .escrow_positions
+                .get(&position_id)
+                .expect("Vote-escrow position not found");
+            Self::escrow_power(&position, Clock::current_time_rounded_to_seconds())
+        }
+
+        /// Returns the current power source registry
+        pub fn get_power_sources(&self) -> Vec<PowerSource> {
+            self.power_sources.clone()
+        }
+
+        /// Returns the power source registry as it was when an item was created
+        pub fn get_item_power_sources(
+            &self,
+            item_kind: ItemKind,
+            item_id: u64,
+        ) -> Vec<PowerSource> {
+            self.power_source_snapshots
+                .get(&(item_kind, item_id))
+                .expect("Item not found")
+                .clone()
+        }
+
+        /// Adds a power source to the registry, or replaces the entry with the same address
+        /// Owner only, while multisig approvals are disabled. Items already created keep their
+        /// snapshot of the registry.
+        pub fn set_power_source(&mut self, source: PowerSource) {
+            self.assert_multisig_disabled();
+            self.set_power_source_internal(source);
+        }
+
+        /// Removes a power source from the registry
+        /// Owner only, while multisig approvals are disabled. Items already created keep their
+        /// snapshot of the registry.
+        pub fn remove_power_source(&mut self, address: GlobalAddress) {
+            self.assert_multisig_disabled();
+            self.remove_power_source_internal(address);
+        }
+
+        /// Returns the badge that authorizes `post_power_root`
+        pub fn get_attestor_badge(&self) -> ResourceAddress {
+            self.attestor_badge
+        }
+
+        /// Replaces the badge that authorizes `post_power_root`
+        /// Owner only, while multisig approvals are disabled
+        pub fn set_attestor_badge(&mut self, badge: ResourceAddress) {
+            self.assert_multisig_disabled();
+            self.set_attestor_badge_internal(badge);
+        }
+
+        /// Updates the governance parameters (owner only, while multisig approvals are disabled)
+        pub fn update_governance_parameters(&mut self, new_params: GovernanceParameters) {
+            self.assert_multisig_disabled();
+            self.update_governance_parameters_internal(new_params);
+        }
+
+        /// Toggles the hidden flag on a temperature check
+        /// Owner only, while multisig approvals are disabled
+        pub fn toggle_temperature_check_hidden(&mut self, temperature_check_id: u64) {
+            self.assert_multisig_disabled();
+            self.toggle_temperature_check_hidden_internal(temperature_check_id);
+        }
+
+        /// Toggles the hidden flag on a proposal
+        /// Owner only, while multisig approvals are disabled
+        pub fn toggle_proposal_hidden(&mut self, proposal_id: u64) {
+            self.assert_multisig_disabled();
+            self.toggle_proposal_hidden_internal(proposal_id);
+        }
+
+        /// Withdraws a temperature check
+        /// Only the author can withdraw, and only before any votes are cast
+        pub fn withdraw_temperature_check(
+            &mut self,
+            author: Global<Account>,
+            temperature_check_id: u64,
+        ) {
+            // Verify the author account is present in the transaction
+            Runtime::assert_access_rule(author.get_owner_role().rule);
+
+            let tc = self
+                .temperature_checks
+                .get(&temperature_check_id)
+                .expect("Temperature check not found");
+
+            assert!(
+                tc.author == author,
+                "Only the author can withdraw a temperature check"
+            );
+            assert!(
+                tc.vote_count == 0,
+                "Cannot withdraw a temperature check after votes have been cast"
+            );
+            drop(tc);
+
+            self.cancel_temperature_check_internal(
+                temperature_check_id,
+                CancelledBy::Author(author),
+                None,
+            );
+            self.update_author_stats(author, |stats| stats.withdrawn += 1);
+        }
+
+        /// Cancels a temperature check (owner only, while multisig approvals are disabled)
+        /// A cancelled temperature check rejects further votes and cannot be elevated
+        pub fn cancel_temperature_check(&mut self, temperature_check_id: u64, reason: String) {
+            self.assert_multisig_disabled();
+            self.cancel_temperature_check_internal(
+                temperature_check_id,
+                CancelledBy::Owner,
+                Some(reason),
+            );
+        }
+
+        /// Cancels a proposal (owner only, while multisig approvals are disabled)
+        /// Only possible while voting is open; a cancelled proposal rejects further votes
+        pub fn cancel_proposal(&mut self, proposal_id: u64, reason: String) {
+            self.assert_multisig_disabled();
+            self.cancel_proposal_internal(proposal_id, CancelledBy::Owner, Some(reason));
+        }
+
+        /// Extends the deadline of an open temperature check
+        /// Owner only, while multisig approvals are disabled. The total extension per item is
+        /// bounded by `max_deadline_extension_minutes`.
+        pub fn extend_temperature_check_deadline(
+            &mut self,
+            temperature_check_id: u64,
+            minutes: u32,
+        ) {
+            self.assert_multisig_disabled();
+            self.extend_temperature_check_deadline_internal(
+                temperature_check_id,
+                minutes,
+                DeadlineExtensionReason::Owner,
+            );
+        }
+
+        /// Extends the deadline of an open proposal
+        /// Owner only, while multisig approvals are disabled. The total extension per item is
+        /// bounded by `max_deadline_extension_minutes`.
+        pub fn extend_proposal_deadline(&mut self, proposal_id: u64, minutes: u32) {
+            self.assert_multisig_disabled();
+            self.extend_proposal_deadline_internal(
+                proposal_id,
+                minutes,
+                DeadlineExtensionReason::Owner,
+            );
+        }
+
+        /// Returns the current emergency pause flags
+        pub fn get_pause_flags(&self) -> PauseFlags {
+            self.pause_flags
+        }
+
+        /// Pauses the parts of the governance flow set in `flags`
+        /// Owner only, while multisig approvals are disabled. Calling this while already paused
+        /// replaces the flags but keeps the original start. A voting flag lifted this way ends
+        /// that kind's voting pause without extending any deadlines; use `unpause` to extend them.
+        pub fn pause(&mut self, flags: PauseFlags) {
+            self.assert_multisig_disabled();
+            self.pause_internal(flags);
+        }
+
+        /// Lifts the pause (owner only, while multisig approvals are disabled)
+        ///
+        /// # Arguments
+        /// * `extend_temperature_check_ids` - Temperature checks whose deadline should move out
+        ///   by the time temperature check voting was paused. Items that had already ended when
+        ///   voting was paused, and cancelled items, are skipped.
+        /// * `extend_proposal_ids` - Proposals whose deadline should move out by the time
+        ///   proposal voting was paused, with the same rules
+        pub fn unpause(
+            &mut self,
+            extend_temperature_check_ids: Vec<u64>,
+            extend_proposal_ids: Vec<u64>,
+        ) {
+            self.assert_multisig_disabled();
+            self.unpause_internal(extend_temperature_check_ids, extend_proposal_ids);
+        }
+
+        /// Sets the first multisig config (owner only)
+        /// Once a config is set, the owner can no longer change it; admins replace or remove it
+        /// through a `SetMultisigConfig` action.
+        pub fn set_multisig_config(&mut self, new_config: Option<MultisigConfig>) {
+            self.assert_multisig_disabled();
+            self.set_multisig_config_internal(new_config);
+        }
+
+        /// Returns the current multisig config
+        pub fn get_multisig_config(&self) -> Option<MultisigConfig> {
+            self.multisig_config.clone()
+        }
+
+        /// Returns a pending action by ID
+        /// An unexecuted action past `expires_at` is reported as `Expired`
+        pub fn get_pending_action(&self, action_id: u64) -> Option<PendingAction> {
+            let now = Clock::current_time_rounded_to_seconds();
+            self.pending_actions.get(&action_id).map(|pending| {
+                let mut pending = pending.clone();
+                pending.status = Self::pending_action_status(&pending, now);
+                pending
+            })
+        }
+
+        /// Returns the current pending action count
+        pub fn get_pending_action_count(&self) -> u64 {
+            self.pending_action_count
+        }
+
+        /// Proposes a privileged action, counting as the first approval
+        /// The action is approved immediately if the threshold is 1
+        ///
+        /// # Arguments
+        /// * `admin_proof` - Proof of exactly one admin badge
+        /// * `action` - The action to execute once the threshold is reached
+        ///
+        /// Returns the ID of the pending action
+        pub fn propose_action(&mut self, admin_proof: Proof, action: GovernanceAction) -> u64 {
+            let admin = self.check_admin_proof(admin_proof);
+            let config = self.multisig_config.clone().unwrap();
+
+            let action_id = self.pending_action_count;
+            self.pending_action_count += 1;
+
+            let now = Clock::current_time_rounded_to_seconds();
+            let expires_at = now.add_days(config.action_validity_days as i64).unwrap();
+
+            self.pending_actions.insert(
+                action_id,
+                PendingAction {
+                    action: action.clone(),
+                    proposed_by: admin.clone(),
+                    approvals: vec![admin.clone()],
+                    threshold: config.threshold,
+                    proposed_at: now,
+                    expires_at,
+                    status: if config.threshold <= 1 {
+                        PendingActionStatus::Approved
+                    } else {
+                        PendingActionStatus::Pending
+                    },
+                },
+            );
+
+            Runtime::emit_event(ActionProposedEvent {
+                action_id,
+                action,
+                proposed_by: admin.clone(),
+                threshold: config.threshold,
+                expires_at,
+            });
+            Runtime::emit_event(ActionApprovedEvent {
+                action_id,
+                approved_by: admin,
+                approval_count: 1,
+            });
+
+            action_id
+        }
+
+        /// Approves a pending action
+        /// The approval that reaches the threshold marks the action approved; it is then
+        /// performed by `execute_action`, so an action that cannot be executed yet does not
+        /// revert the approvals.
+        ///
+        /// # Arguments
+        /// * `admin_proof` - Proof of exactly one admin badge that has not yet approved
+        /// * `action_id` - The ID of the pending action
+        pub fn approve_action(&mut self, admin_proof: Proof, action_id: u64) {
+            let admin = self.check_admin_proof(admin_proof);
+
+            let mut pending = self
+                .pending_actions
+                .get_mut(&action_id)
+                .expect("Pending action not found");
+
+            Self::assert_action_pending(&pending);
+            assert!(
+                !pending.approvals.contains(&admin),
+                "Admin badge has already approved this action"
+            );
+
+            pending.approvals.push(admin.clone());
+            let approval_count = pending.approvals.len() as u32;
+            if approval_count >= pending.threshold {
+                pending.status = PendingActionStatus::Approved;
+            }
+            drop(pending);
+
+            Runtime::emit_event(ActionApprovedEvent {
+                action_id,
+                approved_by: admin,
+                approval_count,
+            });
+        }
+
+        /// Executes an approved action
+        /// Any admin badge can execute it, before `expires_at`. If the action fails, for
+        /// example because its item changed since it was proposed, it stays approved and can
+        /// be retried or cancelled.
+        ///
+        /// # Arguments
+        /// * `admin_proof` - Proof of exactly one admin badge
+        /// * `action_id` - The ID of the approved action
+        pub fn execute_action(&mut self, admin_proof: Proof, action_id: u64) {
+            self.check_admin_proof(admin_proof);
+
+            let pending = self
+                .pending_actions
+                .get(&action_id)
+                .expect("Pending action not found");
+            match Self::pending_action_status(&pending, Clock::current_time_rounded_to_seconds()) {
+                PendingActionStatus::Approved => {}
+                PendingActionStatus::Pending => panic!("Action has not reached its threshold"),
+                PendingActionStatus::Expired => panic!("Action has expired"),
+                _ => panic!("Action is no longer pending"),
+            }
+            drop(pending);
+
+            self.perform_action(action_id);
+        }
+
+        /// Cancels a pending or approved action
+        /// Only the admin badge that proposed the action can cancel it
+        pub fn cancel_action(&mut self, admin_proof: Proof, action_id: u64) {
+            let admin = self.check_admin_proof(admin_proof);
+
+            let mut pending = self
+                .pending_actions
+                .get_mut(&action_id)
+                .expect("Pending action not found");
+
+            match Self::pending_action_status(&pending, Clock::current_time_rounded_to_seconds()) {
+                PendingActionStatus::Pending | PendingActionStatus::Approved => {}
+                PendingActionStatus::Expired => panic!("Action has expired"),
+                _ => panic!("Action is no longer pending"),
+            }
+            assert!(
+                pending.proposed_by == admin,
+                "Only the proposer can cancel an action"
+            );
+
+            pending.status = PendingActionStatus::Cancelled;
+            drop(pending);
+
+            Runtime::emit_event(ActionCancelledEvent { action_id });
+        }
+
+        /// Records a vote on a temperature check
+        /// The caller must have verified that the account is present in the transaction
+        fn cast_temperature_check_vote(
+            &mut self,
+            account: Global<Account>,
+            temperature_check_id: u64,
+            vote: TemperatureCheckVote,
+            rationale: Option<VoteRationale>,
+            claim: PowerClaim,
+            eligibility_proof: Option<Proof>,
+        ) {
+            assert!(
+                !self.pause_flags.temperature_check_voting,
+                "Temperature check voting is paused"
+            );
+
+            self.assert_valid_rationale(&rationale);
+
+            // Get the temperature check
+            let mut tc = self
+                .temperature_checks
+                .get_mut(&temperature_check_id)
+                .expect("Temperature check not found");
+
+            assert!(
+                tc.cancellation.is_none(),
+                "Temperature check has been cancelled"
+            );
+
+            // Check the vote is still open
+            let now = Clock::current_time_rounded_to_seconds();
+            assert!(
+                now.compare(tc.start, TimeComparisonOperator::Gte),
+                "Voting has not started yet"
+            );
+            assert!(
+                now.compare(tc.deadline, TimeComparisonOperator::Lt),
+                "Voting has ended"
+            );
+
+            Self::record_eligibility(
+                &tc.eligibility,
+                eligibility_proof,
+                account,
+                &tc.eligibility_voters,
+                &tc.eligibility_amounts,
+            );
+
+            let position_ids = match &claim {
+                PowerClaim::Position { position_ids, .. } => position_ids.clone(),
+                _ => Vec::new(),
+            };
+            let power = Self::resolve_power(tc.power_mode, tc.power_root, account, claim);
+
+            // A vote-escrow position can only back one account's ballot per item
+            for position_id in &position_ids {
+                let bound_voter = tc.position_voters.get(position_id).map(|voter| *voter);
+                match bound_voter {
+                    Some(voter) => assert!(
+                        voter == account,
+                        "Vote-escrow position has already backed another account's vote"
+                    ),
+                    None => tc.position_voters.insert(position_id.clone(), account),
+                }
+            }
+
+            // Check if the account has already voted (revote scenario)
+            let previous_vote = tc
+                .voters
+                .get(&account)
+                .map(|e| (e.vote_id, e.revotes, e.vote, e.power));
+            let (replacing_vote_id, revotes) = match previous_vote {
+                Some((id, revotes, previous, previous_power)) => {
+                    Self::assert_revote_allowed(tc.revote_policy, revotes, tc.deadline, now);
+                    tc.revote_count += 1;
+                    tc.tally.remove(previous);
+                    tc.power_tally.remove(previous, previous_power);
+                    (Some(id), revotes + 1)
+                }
+                // Voting again after a retraction counts as a change of vote
+                None => match tc.retracted_voters.remove(&account) {
+                    Some(revotes) => {
+                        Self::assert_revote_allowed(tc.revote_policy, revotes, tc.deadline, now);
+                        (None, revotes + 1)
+                    }
+                    None => (None, 0),
+                },
+            };
+
+            // Get the vote ID and increment the counter
+            let vote_id = tc.vote_count;
+            tc.vote_count += 1;
+            tc.tally.add(vote);
+            tc.power_tally.add(vote, power);
+
+            // Record the vote in both stores (insert replaces existing entry for the account)
+            tc.voters.insert(
+                account,
+                TemperatureCheckVoterEntry {
+                    vote_id,
+                    vote,
+                    revotes,
+                    power,
+                    position_ids: position_ids.clone(),
+                },
+            );
+            tc.votes.insert(
+                vote_id,
+                TemperatureCheckVoteRecord {
+                    voter: account,
+                    vote: Some(vote),
+                    replacing_vote_id,
+                    rationale: rationale.clone(),
+                    power,
+                    position_ids: position_ids.clone(),
+                },
+            );
+
+            Runtime::emit_event(TemperatureCheckVotedEvent {
+                temperature_check_id,
+                vote_id,
+                account,
+                vote,
+                replacing_vote_id,
+                rationale,
+                power,
+                position_ids,
+            });
+
+            Self::index_account_vote(
+                &mut self.account_votes,
+                account,
+                AccountVote {
+                    item_kind: ItemKind::TemperatureCheck,
+                    item_id: temperature_check_id,
+                    vote_id,
+                    retracted: false,
+                },
+            );
+
+            // Move the deadline out if the vote arrived within the anti-sniping window
+            let tc = &mut *tc;
+            if let Some((previous_deadline, new_deadline)) = Self::apply_anti_sniping(
+                tc.anti_sniping,
+                &mut tc.deadline,
+                &mut tc.anti_sniping_extension_minutes,
+                now,
+            ) {
+                Runtime::emit_event(DeadlineExtendedEvent {
+                    item_kind: ItemKind::TemperatureCheck,
+                    item_id: temperature_check_id,
+                    previous_deadline,
+                    new_deadline,
+                    reason: DeadlineExtensionReason::AntiSniping,
+                });
+            }
+        }
+
+        /// Records a vote on a proposal
+        /// The caller must have verified that the account is present in the transaction
+        fn cast_proposal_vote(
+            &mut self,
+            account: Global<Account>,
+            proposal_id: u64,
+            options: Vec<ProposalVoteOptionId>,
+            rationale: Option<VoteRationale>,
+            claim: PowerClaim,
+            eligibility_proof: Option<Proof>,
+        ) {
+            assert!(
+                !self.pause_flags.proposal_voting,
+                "Proposal voting is paused"
+            );
+
+            self.assert_valid_rationale(&rationale);
+
+            // Get the proposal
+            let mut proposal = self
+                .proposals
+                .get_mut(&proposal_id)
+                .expect("Proposal not found");
+
+            assert!(
+                proposal.cancellation.is_none(),
+                "Proposal has been cancelled"
+            );
+
+            // Check the vote is still open
+            let now = Clock::current_time_rounded_to_seconds();
+            assert!(
+                now.compare(proposal.start, TimeComparisonOperator::Gte),
+                "Voting has not started yet"
+            );
+            assert!(
+                now.compare(proposal.deadline, TimeComparisonOperator::Lt),
+                "Voting has ended"
+            );
+
+            Self::record_eligibility(
+                &proposal.eligibility,
+                eligibility_proof,
+                account,
+                &proposal.eligibility_voters,
+                &proposal.eligibility_amounts,
+            );
+
+            // Validate option count based on max_selections
+            assert!(!options.is_empty(), "Must select at least one option");
+
+            match proposal.max_selections {
+                None => {
+                    // Single choice: exactly one option
+                    assert!(
+                        options.len() == 1,
+                        "This is a single-choice proposal, select exactly one option"
+                    );
+                }
+                Some(max) => {
+                    // Multiple choice: up to max options
+                    assert!(
+                        options.len() <= max as usize,
+                        "Cannot select more than {} options",
+                        max
+                    );
+                }
+            }
+
+            // Check for duplicate selections
+            let mut seen = Vec::new();
+            for option in &options {
+                assert!(!seen.contains(option), "Duplicate vote option selected");
+                seen.push(*option);
+            }
+
+            // Validate all selected options exist (IDs are 0..vote_option_count)
+            for option in &options {
+                assert!(option.0 < proposal.vote_option_count, "Invalid vote option");
+            }
+
+            // Abstain cannot be combined with other options
+            let is_abstain = match proposal.abstain_option_id {
+                Some(abstain_option_id) if options.contains(&abstain_option_id) => {
+                    assert!(
+                        options.len() == 1,
+                        "Abstain cannot be combined with other options"
+                    );
+                    true
+                }
+                _ => false,
+            };
+
+            // An abstain ballot is exempt from the minimum
+            if let Some(min) = proposal.min_selections {
+                assert!(
+                    is_abstain || options.len() >= min as usize,
+                    "Must select at least {} options",
+                    min
+                );
+            }
+
+            let position_ids = match &claim {
+                PowerClaim::Position { position_ids, .. } => position_ids.clone(),
+                _ => Vec::new(),
+            };
+            let power =
+                Self::resolve_power(proposal.power_mode, proposal.power_root, account, claim);
+
+            // A vote-escrow position can only back one account's ballot per item
+            for position_id in &position_ids {
+                let bound_voter = proposal
+                    .position_voters
+                    .get(position_id)
+                    .map(|voter| *voter);
+                match bound_voter {
+                    Some(voter) => assert!(
+                        voter == account,
+                        "Vote-escrow position has already backed another account's vote"
+                    ),
+                    None => proposal
+                        .position_voters
+                        .insert(position_id.clone(), account),
+                }
+            }
+
+            // Check if the account has already voted (revote scenario)
+            let previous_vote = proposal
+                .voters
+                .get(&account)
+                .map(|e| (e.vote_id, e.revotes, e.options.clone(), e.power));
+            let (replacing_vote_id, revotes) = match previous_vote {
+                Some((id, revotes, previous, previous_power)) => {
+                    Self::assert_revote_allowed(
+                        proposal.revote_policy,
+                        revotes,
+                        proposal.deadline,
+                        now,
+                    );
+                    proposal.revote_count += 1;
+                    for option in previous {
+                        proposal.option_tallies[option.0 as usize] -= 1;
+                        proposal.option_powers[option.0 as usize] -= previous_power;
+                    }
+                    proposal.total_power -= previous_power;
+                    (Some(id), revotes + 1)
+                }
+                // Voting again after a retraction counts as a change of vote
+                None => match proposal.retracted_voters.remove(&account) {
+                    Some(revotes) => {
+                        Self::assert_revote_allowed(
+                            proposal.revote_policy,
+                            revotes,
+                            proposal.deadline,
+                            now,
+                        );
+                        (None, revotes + 1)
+                    }
+                    None => (None, 0),
+                },
+            };
+
+            // Get the vote ID and increment the counter
+            let vote_id = proposal.vote_count;
+            proposal.vote_count += 1;
+            for option in &options {
+                proposal.option_tallies[option.0 as usize] += 1;
+                proposal.option_powers[option.0 as usize] += power;
+            }
+            proposal.total_power += power;
+
+            // Record the vote in both stores (insert replaces existing entry for the account)
+            proposal.voters.insert(
+                account,
+                ProposalVoterEntry {
+                    vote_id,
+                    options: options.clone(),
+                    revotes,
+                    power,
+                    position_ids: position_ids.clone(),
+                },
+            );
+            proposal.votes.insert(
+                vote_id,
+                ProposalVoteRecord {
+                    voter: account,
+                    options: Some(options.clone()),
+                    replacing_vote_id,
+                    rationale: rationale.clone(),
+                    power,
+                    position_ids: position_ids.clone(),
+                },
+            );
+
+            Runtime::emit_event(ProposalVotedEvent {
+                proposal_id,
+                vote_id,
+                account,
+                options,
+                replacing_vote_id,
+                rationale,
+                power,
+                position_ids,
+            });
+
+            Self::index_account_vote(
+                &mut self.account_votes,
+                account,
+                AccountVote {
+                    item_kind: ItemKind::Proposal,
+                    item_id: proposal_id,
+                    vote_id,
+                    retracted: false,
+                },
+            );
+
+            // Move the deadline out if the vote arrived within the anti-sniping window
+            let proposal = &mut *proposal;
+            if let Some((previous_deadline, new_deadline)) = Self::apply_anti_sniping(
+                proposal.anti_sniping,
+                &mut proposal.deadline,
+                &mut proposal.anti_sniping_extension_minutes,
+                now,
+            ) {
+                Runtime::emit_event(DeadlineExtendedEvent {
+                    item_kind: ItemKind::Proposal,
+                    item_id: proposal_id,
+                    previous_deadline,
+                    new_deadline,
+                    reason: DeadlineExtensionReason::AntiSniping,
+                });
+            }
+        }
+
+        /// Asserts that the owner may act directly
+        /// Once a multisig config is set, privileged actions must go through `propose_action`
+        fn assert_multisig_disabled(&self) {
+            assert!(
+                self.multisig_config.is_none(),
+                "Multisig approvals are configured, propose this action instead"
+            );
+        }
+
+        /// Validates an admin badge proof against the multisig config
+        /// Returns the local ID of the proven badge
+        fn check_admin_proof(&self, admin_proof: Proof) -> NonFungibleLocalId {
+            let config = self
+                .multisig_config
+                .as_ref()
+                .expect("Multisig approvals are not configured");
+
+            admin_proof
+                .check_with_message(config.admin_badge, "Invalid admin badge")
+                .as_non_fungible()
+                .non_fungible_local_id()
+        }
+
+        /// Returns the status of a pending action at `now`, accounting for expiry
+        fn pending_action_status(pending: &PendingAction, now: Instant) -> PendingActionStatus {
+            match pending.status {
+                PendingActionStatus::Pending | PendingActionStatus::Approved
+                    if now.compare(pending.expires_at, TimeComparisonOperator::Gte) =>
+                {
+                    PendingActionStatus::Expired
+                }
+                status => status,
+            }
+        }
+
+        /// Asserts that a pending action can still be approved
+        fn assert_action_pending(pending: &PendingAction) {
+            match Self::pending_action_status(pending, Clock::current_time_rounded_to_seconds()) {
+                PendingActionStatus::Pending => {}
+                PendingActionStatus::Expired => panic!("Action has expired"),
+                _ => panic!("Action is no longer pending"),
+            }
+        }
+
+        /// Marks an approved action as executed and performs it
+        fn perform_action(&mut self, action_id: u64) {
+            let mut pending = self.pending_actions.get_mut(&action_id).unwrap();
+            pending.status = PendingActionStatus::Executed;
+            let action = pending.action.clone();
+            drop(pending);
+
+            match action.clone() {
+                GovernanceAction::MakeProposal {
+                    temperature_check_id,
+                    eligibility,
+                } => {
+                    self.make_proposal_internal(temperature_check_id, eligibility);
+                }
+                GovernanceAction::UpdateGovernanceParameters { new_params } => {
+                    self.update_governance_parameters_internal(new_params);
+                }
+                GovernanceAction::ToggleTemperatureCheckHidden {
+                    temperature_check_id,
+                } => {
+                    self.toggle_temperature_check_hidden_internal(temperature_check_id);
+                }
+                GovernanceAction::ToggleProposalHidden { proposal_id } => {
+                    self.toggle_proposal_hidden_internal(proposal_id);
+                }
+                GovernanceAction::CancelTemperatureCheck {
+                    temperature_check_id,
+                    reason,
+                } => {
+                    self.cancel_temperature_check_internal(
+                        temperature_check_id,
+                        CancelledBy::Admins { action_id },
+                        Some(reason),
+                    );
+                }
+                GovernanceAction::CancelProposal {
+                    proposal_id,
+                    reason,
+                } => {
+                    self.cancel_proposal_internal(
+                        proposal_id,
+                        CancelledBy::Admins { action_id },
+                        Some(reason),
+                    );
+                }
+                GovernanceAction::SetPowerSource { source } => {
+                    self.set_power_source_internal(source);
+                }
+                GovernanceAction::RemovePowerSource { address } => {
+                    self.remove_power_source_internal(address);
+                }
+                GovernanceAction::ExtendTemperatureCheckDeadline {
+                    temperature_check_id,
+                    minutes,
+                } => {
+                    self.extend_temperature_check_deadline_internal(
+                        temperature_check_id,
+                        minutes,
+                        DeadlineExtensionReason::Admins { action_id },
+                    );
+                }
+                GovernanceAction::ExtendProposalDeadline {
+                    proposal_id,
+                    minutes,
+                } => {
+                    self.extend_proposal_deadline_internal(
+                        proposal_id,
+                        minutes,
+                        DeadlineExtensionReason::Admins { action_id },
+                    );
+                }
+                GovernanceAction::Pause { flags } => {
+                    self.pause_internal(flags);
+                }
+                GovernanceAction::Unpause {
+                    extend_temperature_check_ids,
+                    extend_proposal_ids,
+                } => {
+                    self.unpause_internal(extend_temperature_check_ids, extend_proposal_ids);
+                }
+                GovernanceAction::SetAttestorBadge { badge } => {
+                    self.set_attestor_badge_internal(badge);
+                }
+                GovernanceAction::SetMultisigConfig { new_config } => {
+                    self.set_multisig_config_internal(new_config);
+                }
+            }
+
+            Runtime::emit_event(ActionExecutedEvent { action_id, action });
+        }
+
+        /// Extends the deadline of an open temperature check, for the owner or an executed
+        /// multisig action
+        fn extend_temperature_check_deadline_internal(
+            &mut self,
+            temperature_check_id: u64,
+            minutes: u32,
+            reason: DeadlineExtensionReason,
+        ) {
+            let max_extension = self.governance_parameters.max_deadline_extension_minutes;
+
+            let mut tc = self
+                .temperature_checks
+                .get_mut(&temperature_check_id)
+                .expect("Temperature check not found");
+
+            assert!(
+                tc.cancellation.is_none(),
+                "Temperature check has been cancelled"
+            );
+            let tc = &mut *tc;
+            let (previous_deadline, new_deadline) = Self::extend_deadline(
+                &mut tc.deadline,
+                &mut tc.deadline_extension_minutes,
+                minutes,
+                max_extension,
+            );
+
+            Runtime::emit_event(DeadlineExtendedEvent {
+                item_kind: ItemKind::TemperatureCheck,
+                item_id: temperature_check_id,
+                previous_deadline,
+                new_deadline,
+                reason,
+            });
+        }
+
+        /// Extends the deadline of an open proposal, for the owner or an executed multisig action
+        fn extend_proposal_deadline_internal(
+            &mut self,
+            proposal_id: u64,
+            minutes: u32,
+            reason: DeadlineExtensionReason,
+        ) {
+            let max_extension = self.governance_parameters.max_deadline_extension_minutes;
+
+            let mut proposal = self
+                .proposals
+                .get_mut(&proposal_id)
+                .expect("Proposal not found");
+
+            assert!(
+                proposal.cancellation.is_none(),
+                "Proposal has been cancelled"
+            );
+            let proposal = &mut *proposal;
+            let (previous_deadline, new_deadline) = Self::extend_deadline(
+                &mut proposal.deadline,
+                &mut proposal.deadline_extension_minutes,
+                minutes,
+                max_extension,
+            );
+
+            Runtime::emit_event(DeadlineExtendedEvent {
+                item_kind: ItemKind::Proposal,
+                item_id: proposal_id,
+                previous_deadline,
+                new_deadline,
+                reason,
+            });
+        }
+
+        /// Sets the pause flags, for the owner or an executed multisig action
+        fn pause_internal(&mut self, flags: PauseFlags) {
+            assert!(flags.any(), "At least one pause flag must be set");
+
+            let now = Clock::current_time_rounded_to_seconds();
+            let paused_at = *self.paused_at.get_or_insert(now);
+            // Deadlines are only extended for the time each kind of voting was paused
+            Self::track_voting_pause(
+                &mut self.temperature_check_voting_paused_at,
+                flags.temperature_check_voting,
+                now,
+            );
+            Self::track_voting_pause(
+                &mut self.proposal_voting_paused_at,
+                flags.proposal_voting,
+                now,
+            );
+            self.pause_flags = flags;
+
+            Runtime::emit_event(PausedEvent { flags, paused_at });
+        }
+
+        /// Lifts the pause, for the owner or an executed multisig action
+        fn unpause_internal(
+            &mut self,
+            extend_temperature_check_ids: Vec<u64>,
+            extend_proposal_ids: Vec<u64>,
+        ) {
+            let paused_at = self.paused_at.take().expect("Governance is not paused");
+            let temperature_check_voting_paused_at = self.temperature_check_voting_paused_at.take();
+            let proposal_voting_paused_at = self.proposal_voting_paused_at.take();
+            self.pause_flags = PauseFlags::default();
+
+            Self::assert_unique_ids(&extend_temperature_check_ids);
+            Self::assert_unique_ids(&extend_proposal_ids);
+            assert!(
+                temperature_check_voting_paused_at.is_some()
+                    || extend_temperature_check_ids.is_empty(),
+                "Temperature check voting was not paused, no deadlines to extend"
+            );
+            assert!(
+                proposal_voting_paused_at.is_some() || extend_proposal_ids.is_empty(),
+                "Proposal voting was not paused, no deadlines to extend"
+            );
+
+            let now = Clock::current_time_rounded_to_seconds();
+
+            let mut extended_temperature_check_ids = Vec::new();
+            if let Some(voting_paused_at) = temperature_check_voting_paused_at {
+                let paused_seconds =
+                    now.seconds_since_unix_epoch - voting_paused_at.seconds_since_unix_epoch;
+
+                for temperature_check_id in extend_temperature_check_ids {
+                    let mut tc = self
+                        .temperature_checks
+                        .get_mut(&temperature_check_id)
+                        .expect("Temperature check not found");
+
+                    if tc.cancellation.is_none()
+                        && tc
+                            .deadline
+                            .compare(voting_paused_at, TimeComparisonOperator::Gt)
+                    {
+                        let previous_deadline = tc.deadline;
+                        tc.deadline = previous_deadline.add_seconds(paused_seconds).unwrap();
+                        extended_temperature_check_ids.push(temperature_check_id);
+
+                        Runtime::emit_event(DeadlineExtendedEvent {
+                            item_kind: ItemKind::TemperatureCheck,
+                            item_id: temperature_check_id,
+                            previous_deadline,
+                            new_deadline: tc.deadline,
+                            reason: DeadlineExtensionReason::Pause,
+                        });
+                    }
+                }
+            }
+
+            let mut extended_proposal_ids = Vec::new();
+            if let Some(voting_paused_at) = proposal_voting_paused_at {
+                let paused_seconds =
+                    now.seconds_since_unix_epoch - voting_paused_at.seconds_since_unix_epoch;
+
+                for proposal_id in extend_proposal_ids {
+                    let mut proposal = self
+                        .proposals
+                        .get_mut(&proposal_id)
+                        .expect("Proposal not found");
+
+                    if proposal.cancellation.is_none()
+                        && proposal
+                            .deadline
+                            .compare(voting_paused_at, TimeComparisonOperator::Gt)
+                    {
+                        let previous_deadline = proposal.deadline;
+                        proposal.deadline = previous_deadline.add_seconds(paused_seconds).unwrap();
+                        extended_proposal_ids.push(proposal_id);
+
+                        Runtime::emit_event(DeadlineExtendedEvent {
+                            item_kind: ItemKind::Proposal,
+                            item_id: proposal_id,
+                            previous_deadline,
+                            new_deadline: proposal.deadline,
+                            reason: DeadlineExtensionReason::Pause,
+                        });
+                    }
+                }
+            }
+
+            Runtime::emit_event(UnpausedEvent {
+                paused_at,
+                temperature_check_voting_paused_at,
+                proposal_voting_paused_at,
+                unpaused_at: now,
+                extended_temperature_check_ids,
+                extended_proposal_ids,
+            });
+        }
+
+        /// Replaces the attestor badge, for the owner or an executed multisig action
+        fn set_attestor_badge_internal(&mut self, badge: ResourceAddress) {
+            self.attestor_badge = badge;
+            Runtime::emit_event(AttestorUpdatedEvent { badge });
+        }
+
+        /// Elevates a temperature check, for the owner or an executed multisig action
+        fn make_proposal_internal(
+            &mut self,
+            temperature_check_id: u64,
+            eligibility: Option<EligibilityRule>,
+        ) -> u64 {
+            assert!(!self.pause_flags.creation, "Creation is paused");
+            Self::assert_valid_eligibility(&eligibility);
+
+            // Get the temperature check
+            let mut tc = self
+                .temperature_checks
+                .get_mut(&temperature_check_id)
+                .expect("Temperature check not found");
+
+            assert!(
+                tc.elevated_proposal_id.is_none(),
+                "Temperature check has already been elevated to a proposal"
+            );
+            assert!(
+                tc.cancellation.is_none(),
+                "Temperature check has been cancelled"
+            );
+
+            let proposal_id = self.proposal_count;
+            self.proposal_count += 1;
+
+            let now = Clock::current_time_rounded_to_seconds();
+            let deadline = now
+                .add_minutes(self.governance_parameters.proposal_length_minutes as i64)
+                .unwrap();
+
+            let proposal = Proposal {
+                vote_option_count: tc.vote_option_count,
+                abstain_option_id: tc.abstain_option_id,
+                option_tallies: vec![0; tc.vote_option_count as usize],
+                power_mode: self.governance_parameters.power_mode,
+                power_root: None,
+                option_powers: vec![Decimal::ZERO; tc.vote_option_count as usize],
+                total_power: Decimal::ZERO,
+                quorum: self.governance_parameters.proposal_quorum,
+                max_selections: tc.max_selections,
+                min_selections: tc.min_selections,
+                eligibility,
+                voters: KeyValueStore::new(),
+                retracted_voters: KeyValueStore::new(),
+                position_voters: KeyValueStore::new(),
+                eligibility_voters: KeyValueStore::new(),
+                eligibility_amounts: KeyValueStore::new(),
+                votes: KeyValueStore::new(),
+                vote_count: 0,
+                revote_count: 0,
+                retraction_count: 0,
+                approval_threshold: self.governance_parameters.proposal_approval_threshold,
+                start: now,
+                deadline,
+                created_epoch: Runtime::current_epoch(),
+                created_transaction_hash: Runtime::transaction_hash(),
+                deadline_extension_minutes: 0,
+                anti_sniping: self.governance_parameters.anti_sniping,
+                anti_sniping_extension_minutes: 0,
+                revote_policy: self.governance_parameters.revote_policy,
+                temperature_check_id,
+                author: tc.author,
+                hidden: false,
+                cancellation: None,
+            };
+
+            tc.elevated_proposal_id = Some(proposal_id);
+            let author = tc.author;
+            drop(tc);
+
+            self.update_author_stats(author, |stats| stats.elevated += 1);
+
+            // The proposal references the temperature check content instead of copying it
+            let content = self
+                .temperature_check_contents
+                .get(&temperature_check_id)
+                .unwrap();
+            let title = content.title.clone();
+            let vote_options = content.vote_options.clone();
+            drop(content);
+            let start = proposal.start;
+            let deadline = proposal.deadline;
+            let created_epoch = proposal.created_epoch;
+            let created_transaction_hash = proposal.created_transaction_hash;
+            let eligibility = proposal.eligibility.clone();
+
+            self.proposals.insert(proposal_id, proposal);
+            let power_sources = self.power_sources.clone();
+            self.power_source_snapshots
+                .insert((ItemKind::Proposal, proposal_id), power_sources.clone());
+
+            Runtime::emit_event(ProposalCreatedEvent {
+                proposal_id,
+                temperature_check_id,
+                title,
+                vote_options,
+                power_sources,
+                start,
+                deadline,
+                created_epoch,
+                created_transaction_hash,
+                eligibility,
+            });
+
+            proposal_id
+        }
+
+        /// Adds or replaces a power source, for the owner or an executed multisig action
+        fn set_power_source_internal(&mut self, source: PowerSource) {
+            assert!(
+                source.weight > Decimal::ZERO,
+                "Power source weight must be positive"
+            );
+
+            match self
+                .power_sources
+                .iter_mut()
+                .find(|existing| existing.address == source.address)
+            {
+                Some(existing) => *existing = source.clone(),
+                None => {
+                    assert!(
+                        self.power_sources.len() < MAX_POWER_SOURCES,
+                        "Too many power sources (max {})",
+                        MAX_POWER_SOURCES
+                    );
+                    self.power_sources.push(source.clone());
+                }
+            }
+
+            Runtime::emit_event(PowerSourceSetEvent { source });
+        }
+
+        /// Removes a power source, for the owner or an executed multisig action
+        fn remove_power_source_internal(&mut self, address: GlobalAddress) {
+            let index = self
+                .power_sources
+                .iter()
+                .position(|source| source.address == address)
+                .expect("Power source not found");
+            self.power_sources.remove(index);
+
+            Runtime::emit_event(PowerSourceRemovedEvent { address });
+        }
+
+        /// Replaces the governance parameters, for the owner or an executed multisig action
+        fn update_governance_parameters_internal(&mut self, new_params: GovernanceParameters) {
+            Self::assert_valid_governance_parameters(&new_params);
+            self.governance_parameters = new_params.clone();
+
+            Runtime::emit_event(GovernanceParametersUpdatedEvent { new_params });
+        }
+
+        /// Toggles a temperature check's hidden flag, for the owner or an executed multisig action
+        fn toggle_temperature_check_hidden_internal(&mut self, temperature_check_id: u64) {
+            let mut tc = self
+                .temperature_checks
+                .get_mut(&temperature_check_id)
+                .expect("Temperature check not found");
+
+            tc.hidden = !tc.hidden;
+            let (author, hidden) = (tc.author, tc.hidden);
+            drop(tc);
+
+            self.update_author_stats(author, |stats| {
+                if hidden {
+                    stats.hidden += 1
+                } else {
+                    stats.hidden -= 1
+                }
+            });
+        }
+
+        /// Toggles a proposal's hidden flag, for the owner or an executed multisig action
+        fn toggle_proposal_hidden_internal(&mut self, proposal_id: u64) {
+            let mut proposal = self
+                .proposals
+                .get_mut(&proposal_id)
+                .expect("Proposal not found");
+
+            proposal.hidden = !proposal.hidden;
+            let (author, hidden) = (proposal.author, proposal.hidden);
+            drop(proposal);
+
+            self.update_author_stats(author, |stats| {
+                if hidden {
+                    stats.hidden += 1
+                } else {
+                    stats.hidden -= 1
+                }
+            });
+        }
+
+        /// Sets or removes the multisig config, for the owner or an executed multisig action
+        /// Pending actions keep the threshold they were proposed with
+        fn set_multisig_config_internal(&mut self, new_config: Option<MultisigConfig>) {
+            if let Some(config) = &new_config {
+                assert!(
+                    config.threshold > 0,
+                    "Multisig threshold must be greater than 0"
+                );
+                assert!(
+                    config.action_validity_days > 0,
+                    "Action validity must be at least one day"
+                );
+                assert!(
+                    !config.admin_badge.is_fungible(),
+                    "Admin badge must be a non-fungible resource"
+                );
+            }
+
+            self.multisig_config = new_config.clone();
+
+            Runtime::emit_event(MultisigConfigUpdatedEvent { new_config });
+        }
+
+        /// Marks a temperature check as cancelled and emits the cancellation event
+        fn cancel_temperature_check_internal(
+            &mut self,
+            temperature_check_id: u64,
+            cancelled_by: CancelledBy,
+            reason: Option<String>,
+        ) {
+            if let Some(reason) = &reason {
+                assert!(!reason.is_empty(), "Cancellation reason cannot be empty");
+            }
+
+            let mut tc = self
+                .temperature_checks
+                .get_mut(&temperature_check_id)
+                .expect("Temperature check not found");
+
+            assert!(
+                tc.cancellation.is_none(),
+                "Temperature check has already been cancelled"
+            );
+            assert!(
+                tc.elevated_proposal_id.is_none(),
+                "Temperature check has already been elevated, cancel the proposal instead"
+            );
+
+            tc.cancellation = Some(Cancellation {
+                cancelled_by: cancelled_by.clone(),
+                reason: reason.clone(),
+                cancelled_at: Clock::current_time_rounded_to_seconds(),
+            });
+
+            Runtime::emit_event(TemperatureCheckCancelledEvent {
+                temperature_check_id,
+                cancelled_by,
+                reason,
+            });
+        }
+
+        /// Marks a proposal as cancelled and emits the cancellation event
+        fn cancel_proposal_internal(
+            &mut self,
+            proposal_id: u64,
+            cancelled_by: CancelledBy,
+            reason: Option<String>,
+        ) {
+            if let Some(reason) = &reason {
+                assert!(!reason.is_empty(), "Cancellation reason cannot be empty");
+            }
+
+            let mut proposal = self
+                .proposals
+                .get_mut(&proposal_id)
+                .expect("Proposal not found");
+
+            assert!(
+                proposal.cancellation.is_none(),
+                "Proposal has already been cancelled"
+            );
+            // A closed proposal's result is final
+            let now = Clock::current_time_rounded_to_seconds();
+            assert!(
+                now.compare(proposal.deadline, TimeComparisonOperator::Lt),
+                "Voting has ended"
+            );
+
+            proposal.cancellation = Some(Cancellation {
+                cancelled_by: cancelled_by.clone(),
+                reason: reason.clone(),
+                cancelled_at: now,
+            });
+
+            Runtime::emit_event(ProposalCancelledEvent {
+                proposal_id,
+                cancelled_by,
+                reason,
+            });
+        }
+
+        /// Moves an open item's deadline out by `minutes`, keeping the total within `max_extension`
+        /// Returns (previous_deadline, new_deadline)
+        fn extend_deadline(
+            deadline: &mut Instant,
+            extension_minutes: &mut u32,
+            minutes: u32,
+            max_extension: u32,
+        ) -> (Instant, Instant) {
+            assert!(minutes > 0, "Extension must be at least one minute");
+
+            let now = Clock::current_time_rounded_to_seconds();
+            assert!(
+                now.compare(*deadline, TimeComparisonOperator::Lt),
+                "Voting has ended"
+            );
+            let total_minutes = extension_minutes
+                .checked_add(minutes)
+                .filter(|total| *total <= max_extension);
+            let Some(total_minutes) = total_minutes else {
+                panic!(
+                    "Deadline cannot be extended by more than {} minutes in total",
+                    max_extension
+                );
+            };
+
+            let previous_deadline = *deadline;
+            *deadline = previous_deadline.add_minutes(minutes as i64).unwrap();
+            *extension_minutes = total_minutes;
+
+            (previous_deadline, *deadline)
+        }
+
+        /// Applies an anti-sniping rule to a vote cast at `now`
+        /// Returns (previous_deadline, new_deadline) if the deadline was moved out
+        fn apply_anti_sniping(
+            rule: Option<AntiSnipingRule>,
+            deadline: &mut Instant,
+            extension_minutes: &mut u32,
+            now: Instant,
+        ) -> Option<(Instant, Instant)> {
+            let rule = rule?;
+
+            let window_start = deadline.add_minutes(-(rule.window_minutes as i64)).unwrap();
+            if now.compare(window_start, TimeComparisonOperator::Lt) {
+                return None;
+            }
+
+            let remaining = rule
+                .max_extension_minutes
+                .saturating_sub(*extension_minutes);
+            let minutes = rule.extension_minutes.min(remaining);
+            if minutes == 0 {
+                return None;
+            }
+
+            let previous_deadline = *deadline;
+            *deadline = previous_deadline.add_minutes(minutes as i64).unwrap();
+            *extension_minutes += minutes;
+
+            Some((previous_deadline, *deadline))
+        }
+
+        /// Panics if the revote policy does not allow another change of vote
+        fn assert_revote_allowed(
+            policy: RevotePolicy,
+            previous_revotes: u32,
+            deadline: Instant,
+            now: Instant,
+        ) {
+            match policy {
+                RevotePolicy::NoRevotes => {
+                    panic!("Votes are final, revoting is not allowed");
+                }
+                RevotePolicy::Unlimited => {}
+                RevotePolicy::AtMost(max) => {
+                    assert!(previous_revotes < max, "Revote limit of {} reached", max);
+                }
+                RevotePolicy::UntilLockWindow { lock_minutes } => {
+                    let lock_start = deadline.add_minutes(-(lock_minutes as i64)).unwrap();
+                    assert!(
+                        now.compare(lock_start, TimeComparisonOperator::Lt),
+                        "Revoting is locked within {} minutes of the deadline",
+                        lock_minutes
+                    );
+                }
+            }
+        }
+
+        /// Asserts that governance parameters describe a usable voting flow
+        /// Applied on instantiation and on every parameter update
+        fn assert_valid_governance_parameters(params: &GovernanceParameters) {
+            assert!(
+                params.temperature_check_length_minutes > 0,
+                "Temperature check length must be at least one minute"
+            );
+            assert!(
+                params.proposal_length_minutes > 0,
+                "Proposal length must be at least one minute"
+            );
+            assert!(
+                params.max_deadline_extension_minutes > 0,
+                "Maximum deadline extension must be at least one minute"
+            );
+            for quorum in [params.temperature_check_quorum, params.proposal_quorum] {
+                assert!(quorum >= Decimal::ZERO, "Quorum cannot be negative");
+            }
+            for threshold in [
+                params.temperature_check_approval_threshold,
+                params.proposal_approval_threshold,
+            ] {
+                assert!(
+                    threshold > Decimal::ZERO && threshold <= Decimal::ONE,
+                    "Approval threshold must be above 0 and at most 1"
+                );
+            }
+            if let Some(rule) = params.anti_sniping {
+                assert!(
+                    rule.window_minutes > 0 && rule.extension_minutes > 0,
+                    "Anti-sniping window and extension must be at least one minute"
+                );
+                assert!(
+                    rule.max_extension_minutes >= rule.extension_minutes,
+                    "Anti-sniping maximum extension must cover at least one extension"
+                );
+            }
+
+            let limits = params.draft_limits;
+            assert!(
+                limits.max_title_bytes > 0
+                    && limits.max_short_description_bytes > 0
+                    && limits.max_inline_description_bytes > 0
+                    && limits.max_option_label_bytes > 0
+                    && limits.max_option_description_bytes > 0,
+                "Draft limits must be positive"
+            );
+            if let Some(label) = &params.abstain_option_label {
+                assert!(
+                    !label.trim().is_empty(),
+                    "Abstain option label cannot be empty"
+                );
+                assert!(
+                    label.len() <= limits.max_option_label_bytes as usize,
+                    "Abstain option label too long (max {} bytes)",
+                    limits.max_option_label_bytes
+                );
+            }
+        }
+
+        /// Panics if the rationale is empty or longer than `max_rationale_length` bytes
+        fn assert_valid_rationale(&self, rationale: &Option<VoteRationale>) {
+            let length = match rationale {
+                None => return,
+                Some(VoteRationale::Text(text)) => text.len(),
+                Some(VoteRationale::Url(url)) => url.as_str().len(),
+            };
+            assert!(length > 0, "Vote rationale cannot be empty");
+            assert!(
+                length <= self.governance_parameters.max_rationale_length as usize,
+                "Vote rationale cannot exceed {} bytes",
+                self.governance_parameters.max_rationale_length
+            );
+        }
+
+        /// Checks an inline description against the byte cap and an off-ledger reference
+        /// for a well-formed location
+        fn assert_valid_description(&self, description: &Description) {
+            match description {
+                Description::Inline(markdown) => {
+                    assert!(
+                        !markdown.is_empty(),
+                        "Temperature check description cannot be empty"
+                    );
+                    let max_bytes = self
+                        .governance_parameters
+                        .draft_limits
+                        .max_inline_description_bytes;
+                    assert!(
+                        markdown.len() <= max_bytes as usize,
+                        "Inline description cannot exceed {} bytes, use a content reference instead",
+                        max_bytes
+                    );
+                }
+                Description::Ref(content_ref) => {
+                    if let Some(ContentLocation::Uri(url)) = &content_ref.location {
+                        Self::assert_allowed_link_scheme(url);
+                    }
+                    if let Some(ContentLocation::IpfsCid(cid)) = &content_ref.location {
+                        assert!(
+                            !cid.is_empty() && cid.len() <= MAX_IPFS_CID_LENGTH,
+                            "IPFS CID must be between 1 and {} bytes",
+                            MAX_IPFS_CID_LENGTH
+                        );
+                        assert!(
+                            cid.chars().all(|c| c.is_ascii_alphanumeric()),
+                            "IPFS CID must be alphanumeric"
+                        );
+                    }
+                }
+            }
+        }
+
+        /// Records the latest vote of an account on an item in the account's vote index
+        /// Takes the index field directly so it can be called while an item is borrowed
+        fn index_account_vote(
+            account_votes: &mut KeyValueStore<Global<Account>, AccountVoteIndex>,
+            account: Global<Account>,
+            vote: AccountVote,
+        ) {
+            if account_votes.get(&account).is_none() {
+                account_votes.insert(
+                    account,
+                    AccountVoteIndex {
+                        positions: KeyValueStore::new(),
+                        history: KeyValueStore::new(),
+                        count: 0,
+                    },
+                );
+            }
+            let mut index = account_votes.get_mut(&account).unwrap();
+
+            let key = (vote.item_kind, vote.item_id);
+            let existing_position = index.positions.get(&key).map(|position| *position);
+            let position = match existing_position {
+                Some(position) => position,
+                None => {
+                    let position = index.count;
+                    index.count += 1;
+                    index.positions.insert(key, position);
+                    position
+                }
+            };
+            index.history.insert(position, vote);
+        }
+
+        /// Appends a new temperature check to its author's index
+        fn index_authored_temperature_check(
+            &mut self,
+            author: Global<Account>,
+            temperature_check_id: u64,
+        ) {
+            if self.authors.get(&author).is_none() {
+                self.authors.insert(
+                    author,
+                    AuthorIndex {
+                        temperature_checks: KeyValueStore::new(),
+                        stats: AuthorStats::default(),
+                    },
+                );
+            }
+            let mut index = self.authors.get_mut(&author).unwrap();
+            let position = index.stats.created;
+            index
+                .temperature_checks
+                .insert(position, temperature_check_id);
+            index.stats.created += 1;
+        }
+
+        /// Applies `update` to the statistics of an author who has created a temperature check
+        fn update_author_stats<F: FnOnce(&mut AuthorStats)>(
+            &mut self,
+            author: Global<Account>,
+            update: F,
+        ) {
+            let mut index = self.authors.get_mut(&author).unwrap();
+            update(&mut index.stats);
+        }
+
+        /// Asserts that an item takes a power root and has neither a root nor votes yet
+        fn assert_power_root_can_be_posted(
+            power_mode: PowerMode,
+            power_root: Option<Hash>,
+            vote_count: u64,
+        ) {
+            assert!(
+                power_mode == PowerMode::MerkleSnapshot,
+                "Item is not in Merkle snapshot power mode"
+            );
+            assert!(power_root.is_none(), "Power root has already been posted");
+            assert!(
+                vote_count == 0,
+                "Power root must be posted before voting starts"
+            );
+        }
+
+        /// Returns the voting power of a ballot, checking the claim against the item's power mode
+        fn resolve_power(
+            power_mode: PowerMode,
+            power_root: Option<Hash>,
+            account: Global<Account>,
+            claim: PowerClaim,
+        ) -> Decimal {
+            match (power_mode, claim) {
+                (PowerMode::Unweighted, PowerClaim::None) => Decimal::ONE,
+                (PowerMode::MerkleSnapshot, PowerClaim::Merkle(proof)) => {
+                    let root = power_root.expect("Power snapshot has not been posted yet");
+                    assert!(proof.power > Decimal::ZERO, "Voting power must be positive");
+                    assert!(
+                        verify_power_proof(root, account.address(), &proof),
+                        "Invalid voting power proof"
+                    );
+                    proof.power
+                }
+                (PowerMode::LsuProofs, PowerClaim::Lsu(proofs)) => Self::lsu_power(proofs),
+                (PowerMode::VoteEscrow, PowerClaim::Position { power, .. }) => {
+                    assert!(
+                        power > Decimal::ZERO,
+                        "Vote-escrow position lock has expired"
+                    );
+                    power
+                }
+                (PowerMode::Unweighted, _) => {
+                    panic!("This item is unweighted and does not accept power claims")
+                }
+                (PowerMode::MerkleSnapshot, _) => {
+                    panic!("This item requires a Merkle power proof")
+                }
+                (PowerMode::LsuProofs, _) => panic!("This item requires LSU proofs"),
+                (PowerMode::VoteEscrow, _) => {
+                    panic!("This item requires a vote-escrow position")
+                }
+            }
+        }
+
+        /// Returns the XRD value of a set of LSU proofs
+        /// Each proof must be of the stake unit of a genuine validator, and each LSU
+        /// resource may be presented only once
+        fn lsu_power(proofs: Vec<Proof>) -> Decimal {
+            assert!(!proofs.is_empty(), "At least one LSU proof is required");
+            assert!(
+                proofs.len() <= MAX_LSU_PROOFS,
+                "Too many LSU proofs (max {})",
+                MAX_LSU_PROOFS
+            );
+
+            let mut seen: Vec<ResourceAddress> = Vec::new();
+            let mut power = Decimal::ZERO;
+            for proof in proofs {
+                // The resource is verified below against its validator
+                let proof = proof.skip_checking();
+                let lsu = proof.resource_address();
+                assert!(!seen.contains(&lsu), "Duplicate LSU proof: {:?}", lsu);
+                seen.push(lsu);
+
+                let validator = Self::lsu_validator(lsu);
+                power += validator.get_redemption_value(proof.amount());
+                proof.drop();
+            }
+
+            assert!(power > Decimal::ZERO, "Voting power must be positive");
+            power
+        }
+
+        /// Returns the validator that issues an LSU resource
+        /// Panics if the resource is not the stake unit of a genuine validator
+        fn lsu_validator(lsu: ResourceAddress) -> Global<Validator> {
+            let validator_address: GlobalAddress = ResourceManager::from(lsu)
+                .get_metadata("validator")
+                .ok()
+                .flatten()
+                .expect("Resource is not a validator stake unit");
+
+            let blueprint_id =
+                ScryptoVmV1Api::object_get_blueprint_id(validator_address.as_node_id());
+            assert!(
+                blueprint_id.package_address == CONSENSUS_MANAGER_PACKAGE
+                    && blueprint_id.blueprint_name == VALIDATOR_BLUEPRINT,
+                "Resource is not a validator stake unit"
+            );
+
+            // The metadata of the resource can be set by anyone who owns it, so
+            // the validator must also name the resource as its stake unit
+            let validator: Global<Validator> = Global::from(ComponentAddress::new_or_panic(
+                validator_address.as_node_id().0,
+            ));
+            let pool_unit: Option<GlobalAddress> =
+                validator.get_metadata("pool_unit").ok().flatten();
+            assert!(
+                pool_unit == Some(lsu.into()),
+                "Resource is not a validator stake unit"
+            );
+
+            validator
+        }
+
+        /// Validates proofs of vote-escrow positions
+        /// A proof may show several positions, and each position may be presented only once.
+        /// Returns the position IDs and their summed current voting power.
+        fn check_escrow_position_proofs(
+            &self,
+            position_proofs: Vec<Proof>,
+        ) -> (Vec<NonFungibleLocalId>, Decimal) {
+            let now = Clock::current_time_rounded_to_seconds();
+            let mut position_ids: Vec<NonFungibleLocalId> = Vec::new();
+            let mut power = Decimal::ZERO;
+            for position_proof in position_proofs {
+                let ids = position_proof
+                    .check_with_message(
+                        self.escrow_position_resource.address(),
+                        "Invalid vote-escrow position",
+                    )
+                    .as_non_fungible()
+                    .non_fungible_local_ids();
+                for position_id in ids {
+                    assert!(
+                        !position_ids.contains(&position_id),
+                        "Duplicate vote-escrow position: {:?}",
+                        position_id
+                    );
+                    let position = self
+                        .escrow_positions
+                        .get(&position_id)
+                        .expect("Vote-escrow position not found");
+                    let position_power = Self::escrow_power(&position, now);
+                    drop(position);
+
+                    // An expired position adds nothing but would still be held by the ballot
+                    assert!(
+                        position_power > Decimal::ZERO,
+                        "Vote-escrow position lock has expired"
+                    );
+                    power += position_power;
+                    position_ids.push(position_id);
+                }
+            }
+
+            assert!(
+                !position_ids.is_empty(),
+                "At least one vote-escrow position is required"
+            );
+            assert!(
+                position_ids.len() <= MAX_BALLOT_POSITIONS,
+                "Too many vote-escrow positions (max {})",
+                MAX_BALLOT_POSITIONS
+            );
+
+            (position_ids, power)
+        }
+
+        /// Returns the voting power of a vote-escrow position at `now`
+        /// The XRD value of the locked tokens, decaying linearly to zero at unlock
+        fn escrow_power(position: &EscrowPosition, now: Instant) -> Decimal {
+            let remaining =
+                position.unlock_at.seconds_since_unix_epoch - now.seconds_since_unix_epoch;
+            if remaining <= 0 {
+                return Decimal::ZERO;
+            }
+            let max_lock = MAX_ESCROW_LOCK_DAYS as i64 * 24 * 60 * 60;
+
+            let value = if position.locked_resource == XRD {
+                position.amount
+            } else {
+                Self::lsu_validator(position.locked_resource).get_redemption_value(position.amount)
+            };
+            value * Decimal::from(remaining.min(max_lock)) / Decimal::from(max_lock)
+        }
+
+        /// Adds an item to the items a vote-escrow position has voted on
+        /// Items that have closed are pruned, so the list only grows with open items
+        fn record_escrow_vote(
+            &mut self,
+            position_id: &NonFungibleLocalId,
+            item_kind: ItemKind,
+            item_id: u64,
+        ) {
+            let now = Clock::current_time_rounded_to_seconds();
+            let previous = self
+                .escrow_positions
+                .get(position_id)
+                .unwrap()
+                .voted_items
+                .clone();
+            let mut voted_items: Vec<(ItemKind, u64)> = previous
+                .into_iter()
+                .filter(|(kind, id)| self.is_item_open(*kind, *id, now))
+                .collect();
+            if !voted_items.contains(&(item_kind, item_id)) {
+                voted_items.push((item_kind, item_id));
+            }
+
+            self.escrow_positions
+                .get_mut(position_id)
+                .unwrap()
+                .voted_items = voted_items;
+        }
+
+        /// Returns true if an item has not been cancelled and its deadline has not passed
+        fn is_item_open(&self, item_kind: ItemKind, item_id: u64, now: Instant) -> bool {
+            let (cancelled, deadline) = match item_kind {
+                ItemKind::TemperatureCheck => {
+                    let tc = self.temperature_checks.get(&item_id).unwrap();
+                    (tc.cancellation.is_some(), tc.deadline)
+                }
+                ItemKind::Proposal => {
+                    let proposal = self.proposals.get(&item_id).unwrap();
+                    (proposal.cancellation.is_some(), proposal.deadline)
+                }
+            };
+            !cancelled && now.compare(deadline, TimeComparisonOperator::Lt)
+        }
+
+        /// Returns the exclusive end of a page of sequential IDs, capped at `count`
+        fn page_end(from_id: u64, limit: u32, count: u64) -> u64 {
+            assert!(
+                limit <= MAX_PAGE_SIZE,
+                "Page size cannot exceed {}",
+                MAX_PAGE_SIZE
+            );
+            from_id.saturating_add(limit as u64).min(count)
+        }
+
+        /// Starts a kind of voting's pause when its flag is set, keeping an earlier start,
+        /// and clears it when the flag is lifted
+        fn track_voting_pause(voting_paused_at: &mut Option<Instant>, paused: bool, now: Instant) {
+            if paused {
+                voting_paused_at.get_or_insert(now);
+            } else {
+                *voting_paused_at = None;
+            }
+        }
+
+        /// Asserts that a list of item IDs contains no duplicates
+        fn assert_unique_ids(ids: &[u64]) {
+            let mut seen: Vec<u64> = Vec::new();
+            for id in ids {
+                assert!(!seen.contains(id), "Duplicate item ID: {}", id);
+                seen.push(*id);
+            }
+        }
+
+        /// Asserts that an eligibility rule can be satisfied
+        fn assert_valid_eligibility(eligibility: &Option<EligibilityRule>) {
+            if let Some(EligibilityRule::Resource { min_amount, .. }) = eligibility {
+                assert!(
+                    *min_amount > Decimal::ZERO,
+                    "Eligibility minimum amount must be positive"
+                );
+            }
+        }
+
+        /// Checks a ballot's eligibility proof against the item's rule and records the holding
+        /// Presented non-fungibles are bound to the account, so they can only back one account
+        /// per item. Fungible amounts cannot be bound and are recorded per account instead.
+        fn record_eligibility(
+            eligibility: &Option<EligibilityRule>,
+            eligibility_proof: Option<Proof>,
+            account: Global<Account>,
+            eligibility_voters: &KeyValueStore<NonFungibleLocalId, Global<Account>>,
+            eligibility_amounts: &KeyValueStore<Global<Account>, Decimal>,
+        ) {
+            let Some(eligibility) = eligibility else {
+                assert!(
+                    eligibility_proof.is_none(),
+                    "This item is not gated and takes no eligibility proof"
+                );
+                return;
+            };
+
+            match Self::check_eligibility_proof(eligibility, eligibility_proof) {
+                EligibilityHolding::Badges(badge_ids) => {
+                    for badge_id in badge_ids {
+                        let bound_voter = eligibility_voters.get(&badge_id).map(|voter| *voter);
+                        match bound_voter {
+                            Some(voter) => assert!(
+                                voter == account,
+                                "Eligibility badge has already been used by another account"
+                            ),
+                            None => eligibility_voters.insert(badge_id, account),
+                        }
+                    }
+                }
+                EligibilityHolding::Amount(amount) => eligibility_amounts.insert(account, amount),
+            }
+        }
+
+        /// Checks an eligibility proof against an item's rule
+        /// Returns the holding the proof shows
+        fn check_eligibility_proof(
+            eligibility: &EligibilityRule,
+            eligibility_proof: Option<Proof>,
+        ) -> EligibilityHolding {
+            let proof = eligibility_proof.expect("This item requires an eligibility proof");
+            match eligibility {
+                EligibilityRule::Resource {
+                    resource,
+                    min_amount,
+                } => {
+                    let proof = proof.check_with_message(*resource, "Invalid eligibility proof");
+                    assert!(
+                        proof.amount() >= *min_amount,
+                        "Eligibility proof must show at least {}",
+                        min_amount
+                    );
+                    if resource.is_fungible() {
+                        EligibilityHolding::Amount(proof.amount())
+                    } else {
+                        EligibilityHolding::Badges(
+                            proof
+                                .as_non_fungible()
+                                .non_fungible_local_ids()
+                                .into_iter()
+                                .collect(),
+                        )
+                    }
+                }
+                EligibilityRule::NonFungible(id) => {
+                    let proof = proof
+                        .check_with_message(id.resource_address(), "Invalid eligibility proof");
+                    assert!(
+                        proof
+                            .as_non_fungible()
+                            .non_fungible_local_ids()
+                            .contains(id.local_id()),
+                        "Eligibility proof does not contain the required badge"
+                    );
+                    EligibilityHolding::Badges(vec![id.local_id().clone()])
+                }
+            }
+        }
+
+        /// Asserts that a display color is a hex code of the form "#RRGGBB"
+        fn assert_valid_color(color: &str) {
+            assert!(
+                color.len() == 7
+                    && color.starts_with('#')
+                    && color[1..].chars().all(|c| c.is_ascii_hexdigit()),
+                "Invalid vote option color: {}",
+                color
+            );
+        }
+
+        /// Asserts that a link uses the allowed URL scheme
+        fn assert_allowed_link_scheme(url: &Url) {
+            assert!(
+                url.as_str().starts_with(ALLOWED_LINK_SCHEME),
+                "Link must use the {} scheme: {}",
+                ALLOWED_LINK_SCHEME,
+                url.as_str()
+            );
         }
     }
 }
@@ -14,13 +14,251 @@ pub enum TemperatureCheckVote {
     Against,
 }
 
+/// Kind of governance item, used where temperature checks and proposals share an API
+#[derive(ScryptoSbor, ManifestSbor, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ItemKind {
+    TemperatureCheck,
+    Proposal,
+}
+
+/// Why the deadline of a temperature check or proposal was moved out
+#[derive(ScryptoSbor, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DeadlineExtensionReason {
+    /// Extended by the owner, bounded by `max_deadline_extension_minutes`
+    Owner,
+    /// Extended by the paused duration when lifting an emergency pause
+    Pause,
+    /// Extended automatically because a vote arrived close to the deadline
+    AntiSniping,
+    /// Extended by an executed multisig action, bounded like owner extensions
+    Admins { action_id: u64 },
+}
+
+/// Public explanation a voter attaches to their ballot
+#[derive(ScryptoSbor, ManifestSbor, Clone, Debug, PartialEq, Eq)]
+pub enum VoteRationale {
+    Text(String),
+    /// Link to an explanation published elsewhere
+    Url(Url),
+}
+
+/// Where off-ledger content can be fetched from
+#[derive(ScryptoSbor, ManifestSbor, Clone, Debug, PartialEq, Eq)]
+pub enum ContentLocation {
+    Uri(Url),
+    /// IPFS content identifier (CIDv0 or CIDv1)
+    IpfsCid(String),
+}
+
+/// Commitment to content stored off-ledger
+/// Clients verify fetched content against `hash` (Blake2b-256 of the raw bytes)
+#[derive(ScryptoSbor, ManifestSbor, Clone, Debug, PartialEq, Eq)]
+pub struct ContentRef {
+    pub hash: Hash,
+    /// Where to fetch the content, None if it is distributed by other means
+    pub location: Option<ContentLocation>,
+}
+
+/// Full description of a temperature check or proposal
+#[derive(ScryptoSbor, ManifestSbor, Clone, Debug, PartialEq, Eq)]
+pub enum Description {
+    /// Markdown stored on-ledger, bounded by `DraftLimits::max_inline_description_bytes`
+    Inline(String),
+    /// Markdown stored off-ledger, committed to by its hash
+    Ref(ContentRef),
+}
+
+/// Reference to the RFC a temperature check is based on
+#[derive(ScryptoSbor, ManifestSbor, Clone, Debug, PartialEq, Eq)]
+pub struct RfcReference {
+    pub url: Url,
+    /// ID of the forum thread discussing the RFC
+    pub forum_thread_id: Option<u64>,
+}
+
+/// Supporting document attached to a temperature check
+/// Clients verify the document fetched from `url` against `content_hash` (Blake2b-256)
+#[derive(ScryptoSbor, ManifestSbor, Clone, Debug, PartialEq, Eq)]
+pub struct Attachment {
+    pub name: String,
+    pub url: Url,
+    pub content_hash: Hash,
+    /// MIME type of the document, e.g. "application/pdf"
+    pub media_type: String,
+}
+
+/// How voting power is established for an item
+#[derive(ScryptoSbor, ManifestSbor, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PowerMode {
+    /// Every account counts with a power of 1; weighted results are computed off-ledger
+    Unweighted,
+    /// Voters prove their power against a Merkle root of (account, power) leaves
+    /// posted by the attestor before voting starts
+    MerkleSnapshot,
+    /// Voters present proofs of validator stake units (LSUs), converted to XRD
+    /// at each validator's redemption value when the ballot is cast
+    LsuProofs,
+    /// Voters present a vote-escrow position, whose power decays until it unlocks
+    VoteEscrow,
+}
+
+/// Kind of asset a registered power source counts
+#[derive(ScryptoSbor, ManifestSbor, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PowerSourceKind {
+    Xrd,
+    /// Stake units of a validator
+    Lsu,
+    /// Pool units of a native pool
+    PoolUnit,
+    /// Liquidity position of a DEX, e.g. Caviarnine or Ociswap
+    LiquidityPosition,
+}
+
+/// A resource or component whose holdings count towards voting power
+#[derive(ScryptoSbor, ManifestSbor, Clone, Debug, PartialEq, Eq)]
+pub struct PowerSource {
+    /// Resource address of the asset, or component address of the pool or DEX it is held in
+    pub address: GlobalAddress,
+    pub kind: PowerSourceKind,
+    /// Multiplier applied to the XRD value of the holdings
+    pub weight: Decimal,
+}
+
+/// Proof that an account is in an item's power snapshot with the given power
+#[derive(ScryptoSbor, ManifestSbor, Clone, Debug)]
+pub struct MerklePowerProof {
+    pub power: Decimal,
+    /// Sibling hashes on the path from the leaf up to the root
+    pub siblings: Vec<Hash>,
+}
+
+/// Voting power claimed with a ballot, resolved against the item's power mode
+pub enum PowerClaim {
+    None,
+    Merkle(MerklePowerProof),
+    Lsu(Vec<Proof>),
+    /// Vote-escrow positions already checked against the component, with their summed
+    /// current power
+    Position {
+        position_ids: Vec<NonFungibleLocalId>,
+        power: Decimal,
+    },
+}
+
+/// Holding shown by a checked eligibility proof
+pub enum EligibilityHolding {
+    /// Local IDs of the presented non-fungibles, bound to the voter
+    Badges(Vec<NonFungibleLocalId>),
+    /// Amount of a fungible resource, recorded per voter
+    Amount(Decimal),
+}
+
+/// Non-fungible data of a vote-escrow position, for display in wallets
+#[derive(ScryptoSbor, NonFungibleData)]
+pub struct EscrowPositionData {
+    pub locked_resource: ResourceAddress,
+    pub amount: Decimal,
+    pub unlock_at: Instant,
+}
+
+/// State of a vote-escrow position, kept by the governance component
+#[derive(ScryptoSbor, Clone, Debug, PartialEq, Eq)]
+pub struct EscrowPosition {
+    /// XRD or the stake unit (LSU) of a validator
+    pub locked_resource: ResourceAddress,
+    pub amount: Decimal,
+    pub locked_at: Instant,
+    pub unlock_at: Instant,
+    /// Items the position has backed a ballot on that may still be open.
+    /// The position cannot be withdrawn until all of them have closed.
+    pub voted_items: Vec<(ItemKind, u64)>,
+}
+
+/// Returns the Merkle leaf committing to an account's voting power
+pub fn power_leaf(account: ComponentAddress, power: Decimal) -> Hash {
+    hash(scrypto_encode(&(account, power)).unwrap())
+}
+
+/// Returns the parent of two Merkle nodes; pairs are hashed in sorted order
+pub fn merkle_parent(a: Hash, b: Hash) -> Hash {
+    let (left, right) = if a.0 <= b.0 { (a, b) } else { (b, a) };
+    hash([left.0, right.0].concat())
+}
+
+/// Returns true if `proof` shows `account` has `proof.power` in the tree with `root`
+pub fn verify_power_proof(root: Hash, account: ComponentAddress, proof: &MerklePowerProof) -> bool {
+    let computed = proof
+        .siblings
+        .iter()
+        .fold(power_leaf(account, proof.power), |node, sibling| {
+            merkle_parent(node, *sibling)
+        });
+    computed == root
+}
+
 /// A recorded vote on a temperature check
 #[derive(ScryptoSbor, Clone, Debug)]
 pub struct TemperatureCheckVoteRecord {
     pub voter: Global<Account>,
-    pub vote: TemperatureCheckVote,
-    /// If this vote replaces a previous vote, this is the ID of the replaced vote
+    /// None if this record retracts the vote `replacing_vote_id`
+    pub vote: Option<TemperatureCheckVote>,
+    /// If this vote replaces or retracts a previous vote, this is the ID of that vote
     pub replacing_vote_id: Option<u64>,
+    pub rationale: Option<VoteRationale>,
+    /// Voting power of the ballot (1 on unweighted items); for a retraction, the power withdrawn
+    pub power: Decimal,
+    /// Vote-escrow positions backing the ballot, empty outside `VoteEscrow` items
+    pub position_ids: Vec<NonFungibleLocalId>,
+}
+
+/// Number of accounts currently voting each way on a temperature check (unweighted)
+#[derive(ScryptoSbor, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TemperatureCheckTally {
+    pub for_votes: u64,
+    pub against_votes: u64,
+}
+
+impl TemperatureCheckTally {
+    /// Counts one more account voting `vote`
+    pub fn add(&mut self, vote: TemperatureCheckVote) {
+        match vote {
+            TemperatureCheckVote::For => self.for_votes += 1,
+            TemperatureCheckVote::Against => self.against_votes += 1,
+        }
+    }
+
+    /// Counts one account fewer voting `vote`
+    pub fn remove(&mut self, vote: TemperatureCheckVote) {
+        match vote {
+            TemperatureCheckVote::For => self.for_votes -= 1,
+            TemperatureCheckVote::Against => self.against_votes -= 1,
+        }
+    }
+}
+
+/// Voting power currently behind each side of a temperature check
+#[derive(ScryptoSbor, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TemperatureCheckPowerTally {
+    pub for_power: Decimal,
+    pub against_power: Decimal,
+}
+
+impl TemperatureCheckPowerTally {
+    /// Adds `power` to the side of `vote`
+    pub fn add(&mut self, vote: TemperatureCheckVote, power: Decimal) {
+        match vote {
+            TemperatureCheckVote::For => self.for_power += power,
+            TemperatureCheckVote::Against => self.against_power += power,
+        }
+    }
+
+    /// Removes `power` from the side of `vote`
+    pub fn remove(&mut self, vote: TemperatureCheckVote, power: Decimal) {
+        match vote {
+            TemperatureCheckVote::For => self.for_power -= power,
+            TemperatureCheckVote::Against => self.against_power -= power,
+        }
+    }
 }
 
 /// Voter entry for temperature checks - combines vote_id with vote data
@@ -29,15 +267,27 @@ pub struct TemperatureCheckVoteRecord {
 pub struct TemperatureCheckVoterEntry {
     pub vote_id: u64,
     pub vote: TemperatureCheckVote,
+    /// Number of times this account has changed its vote
+    pub revotes: u32,
+    /// Voting power counted for this vote in the item's power tally
+    pub power: Decimal,
+    /// Vote-escrow positions backing the vote, empty outside `VoteEscrow` items
+    pub position_ids: Vec<NonFungibleLocalId>,
 }
 
 /// A recorded vote on a proposal
 #[derive(ScryptoSbor, Clone, Debug)]
 pub struct ProposalVoteRecord {
     pub voter: Global<Account>,
-    pub options: Vec<ProposalVoteOptionId>,
-    /// If this vote replaces a previous vote, this is the ID of the replaced vote
+    /// None if this record retracts the vote `replacing_vote_id`
+    pub options: Option<Vec<ProposalVoteOptionId>>,
+    /// If this vote replaces or retracts a previous vote, this is the ID of that vote
     pub replacing_vote_id: Option<u64>,
+    pub rationale: Option<VoteRationale>,
+    /// Voting power of the ballot (1 on unweighted items); for a retraction, the power withdrawn
+    pub power: Decimal,
+    /// Vote-escrow positions backing the ballot, empty outside `VoteEscrow` items
+    pub position_ids: Vec<NonFungibleLocalId>,
 }
 
 /// Voter entry for proposals - combines vote_id with vote data
@@ -46,24 +296,132 @@ pub struct ProposalVoteRecord {
 pub struct ProposalVoterEntry {
     pub vote_id: u64,
     pub options: Vec<ProposalVoteOptionId>,
+    /// Number of times this account has changed its vote
+    pub revotes: u32,
+    /// Voting power counted for each selected option in the item's power tally
+    pub power: Decimal,
+    /// Vote-escrow positions backing the vote, empty outside `VoteEscrow` items
+    pub position_ids: Vec<NonFungibleLocalId>,
+}
+
+/// Whether and until when voters can change their vote on an item
+#[derive(ScryptoSbor, ManifestSbor, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RevotePolicy {
+    /// Votes are final
+    NoRevotes,
+    Unlimited,
+    /// Each account can change its vote at most this many times
+    AtMost(u32),
+    /// Votes can be changed until this many minutes before the deadline
+    UntilLockWindow {
+        lock_minutes: u32,
+    },
+}
+
+/// Entry in an account's vote index: the account's latest vote record on an item
+#[derive(ScryptoSbor, Clone, Debug, PartialEq, Eq)]
+pub struct AccountVote {
+    pub item_kind: ItemKind,
+    pub item_id: u64,
+    /// ID of the account's latest vote record on the item
+    pub vote_id: u64,
+    /// True if the latest record retracts the account's vote
+    pub retracted: bool,
+}
+
+/// Index from an account to the items it has voted on
+#[derive(ScryptoSbor)]
+pub struct AccountVoteIndex {
+    /// Maps (item kind, item ID) to the item's position in `history`
+    pub positions: KeyValueStore<(ItemKind, u64), u64>,
+    /// Entries ordered by the account's first vote on each item
+    pub history: KeyValueStore<u64, AccountVote>,
+    pub count: u64,
+}
+
+/// Track record of a temperature check author
+#[derive(ScryptoSbor, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AuthorStats {
+    /// Temperature checks created
+    pub created: u64,
+    /// Temperature checks elevated to a proposal
+    pub elevated: u64,
+    /// Temperature checks and proposals currently hidden
+    pub hidden: u64,
+    /// Temperature checks withdrawn by the author
+    pub withdrawn: u64,
+}
+
+/// Index of the temperature checks created by an author
+#[derive(ScryptoSbor)]
+pub struct AuthorIndex {
+    /// Temperature check IDs in creation order; `stats.created` is the length
+    pub temperature_checks: KeyValueStore<u64, u64>,
+    pub stats: AuthorStats,
+}
+
+/// A temperature check created by an author and the proposal it led to, if any
+#[derive(ScryptoSbor, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AuthorItem {
+    pub temperature_check_id: u64,
+    pub proposal_id: Option<u64>,
 }
 
 /// Unique identifier for a proposal vote option
 #[derive(ScryptoSbor, ManifestSbor, Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct ProposalVoteOptionId(pub u32);
 
-/// Input for creating a vote option (user provides label only, ID is auto-generated)
+/// Input for creating a vote option (the ID is auto-generated)
 #[derive(ScryptoSbor, ManifestSbor, Clone, Debug)]
 pub struct ProposalVoteOptionInput {
     pub label: String,
+    /// Longer explanation of what choosing this option means
+    pub description: Option<String>,
+    /// Display color as a hex code, e.g. "#00C389"
+    pub color: Option<String>,
+    /// Link to more details about this option
+    pub link: Option<Url>,
 }
 
 /// A vote option for proposals (e.g., "For", "Against")
 /// The ID is auto-generated based on the order of options (0, 1, 2, ...)
-#[derive(ScryptoSbor, ManifestSbor, Clone, Debug)]
+#[derive(ScryptoSbor, ManifestSbor, Clone, Debug, PartialEq, Eq)]
 pub struct ProposalVoteOption {
     pub id: ProposalVoteOptionId,
     pub label: String,
+    pub description: Option<String>,
+    pub color: Option<String>,
+    pub link: Option<Url>,
+    /// True for the abstain option appended by the blueprint
+    pub is_abstain: bool,
+}
+
+/// The vote in a batched ballot; the variant determines which kind of item it targets
+#[derive(ScryptoSbor, ManifestSbor, Clone, Debug)]
+pub enum BallotVote {
+    TemperatureCheck(TemperatureCheckVote),
+    Proposal(Vec<ProposalVoteOptionId>),
+}
+
+impl BallotVote {
+    /// Returns the kind of item this vote targets
+    pub fn item_kind(&self) -> ItemKind {
+        match self {
+            BallotVote::TemperatureCheck(_) => ItemKind::TemperatureCheck,
+            BallotVote::Proposal(_) => ItemKind::Proposal,
+        }
+    }
+}
+
+/// One ballot in a batch vote
+#[derive(ScryptoSbor, Clone, Debug)]
+pub struct BallotInput {
+    /// The account casting the vote (must prove its presence)
+    pub account: Global<Account>,
+    /// ID of the temperature check or proposal, depending on the `vote` variant
+    pub item_id: u64,
+    pub vote: BallotVote,
+    pub rationale: Option<VoteRationale>,
 }
 
 /// Maximum number of links per temperature check / proposal
@@ -72,6 +430,28 @@ pub const MAX_LINKS: usize = 10;
 pub const MAX_VOTE_OPTIONS: usize = 10;
 /// Maximum number of selections in a multiple-choice vote
 pub const MAX_SELECTIONS: u32 = 5;
+/// Maximum number of ballots in a single batch vote
+pub const MAX_BATCH_BALLOTS: usize = 20;
+/// Maximum number of entries returned by a paginated getter
+pub const MAX_PAGE_SIZE: u32 = 100;
+/// Maximum length in bytes of an IPFS content identifier
+pub const MAX_IPFS_CID_LENGTH: usize = 128;
+/// Maximum number of attachments per temperature check / proposal
+pub const MAX_ATTACHMENTS: usize = 10;
+/// Maximum length in bytes of an attachment name
+pub const MAX_ATTACHMENT_NAME_LENGTH: usize = 200;
+/// Maximum length in bytes of an attachment media type
+pub const MAX_MEDIA_TYPE_LENGTH: usize = 127;
+/// URL scheme prefix required for links and off-ledger content locations
+pub const ALLOWED_LINK_SCHEME: &str = "https://";
+/// Maximum number of LSU proofs accepted with a single ballot
+pub const MAX_LSU_PROOFS: usize = 20;
+/// Maximum number of vote-escrow positions backing a single ballot
+pub const MAX_BALLOT_POSITIONS: usize = 20;
+/// Maximum number of entries in the power source registry
+pub const MAX_POWER_SOURCES: usize = 50;
+/// Maximum lock duration of a vote-escrow position; a position locked this long has full power
+pub const MAX_ESCROW_LOCK_DAYS: u32 = 4 * 365;
 
 // =============================================================================
 // Delegation Constants
@@ -92,95 +472,503 @@ pub struct TemperatureCheckDraft {
     pub title: String,
     /// Short summary of the proposal
     pub short_description: String,
-    /// Full description in markdown format
-    pub description: String,
+    /// Full description in markdown format, inline or as an off-ledger reference
+    pub description: Description,
     /// Vote options with labels and colors (IDs are auto-generated)
     pub vote_options: Vec<ProposalVoteOptionInput>,
     /// External links related to the proposal
     pub links: Vec<Url>,
+    /// The RFC this temperature check is based on
+    pub rfc_reference: Option<RfcReference>,
+    /// Supporting documents (max `MAX_ATTACHMENTS`)
+    pub attachments: Vec<Attachment>,
     /// Maximum number of options a voter can select in the proposal.
     /// If None, only one option can be selected (single choice).
     /// If Some(n), up to n options can be selected (multiple choice).
     pub max_selections: Option<u32>,
+    /// Minimum number of options a voter must select in a multiple-choice proposal.
+    /// If None, at least one option must be selected.
+    pub min_selections: Option<u32>,
+    /// Holding voters must prove to vote. If None, every account can vote.
+    pub eligibility: Option<EligibilityRule>,
+    /// When voting opens. If None, voting opens immediately.
+    /// A future start leaves a review period, bounded by `max_start_delay_minutes`.
+    pub start: Option<Instant>,
+}
+
+/// Holding a voter must prove to vote on a gated item
+/// Presented non-fungibles are bound to the first account using them. Fungible holdings cannot
+/// be bound: the same tokens can be moved to another account and proven again, so each voter's
+/// proven amount is recorded for auditing instead.
+#[derive(ScryptoSbor, ManifestSbor, Clone, Debug, PartialEq, Eq)]
+pub enum EligibilityRule {
+    /// At least `min_amount` of a resource, e.g. any validator owner badge or 1000 of a token
+    Resource {
+        resource: ResourceAddress,
+        min_amount: Decimal,
+    },
+    /// One specific non-fungible, e.g. a council member NFT
+    NonFungible(NonFungibleGlobalId),
+}
+
+/// Rule that moves the deadline out when a vote arrives shortly before it,
+/// so late swings can still be answered
+#[derive(ScryptoSbor, ManifestSbor, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AntiSnipingRule {
+    /// A vote within this many minutes of the deadline triggers an extension
+    pub window_minutes: u32,
+    /// How far each triggering vote moves the deadline out
+    pub extension_minutes: u32,
+    /// Cap on the total automatic extension per item
+    pub max_extension_minutes: u32,
 }
 
 /// Governance parameters that control voting behavior
 #[derive(ScryptoSbor, ManifestSbor, Clone, Debug)]
 pub struct GovernanceParameters {
-    pub temperature_check_days: u16,
+    pub temperature_check_length_minutes: u32,
     pub temperature_check_quorum: Decimal,
     pub temperature_check_approval_threshold: Decimal,
-    pub proposal_length_days: u16,
+    pub proposal_length_minutes: u32,
     pub proposal_quorum: Decimal,
     pub proposal_approval_threshold: Decimal,
+    /// How far in the future a temperature check draft may schedule its start
+    pub max_start_delay_minutes: u32,
+    /// Total amount the owner may extend a single item's deadline by
+    pub max_deadline_extension_minutes: u32,
+    /// Automatic deadline extension for late votes, None to disable
+    pub anti_sniping: Option<AntiSnipingRule>,
+    /// Revote policy applied to newly created items
+    pub revote_policy: RevotePolicy,
+    /// Maximum length in bytes of a vote rationale (text or URL)
+    pub max_rationale_length: u32,
+    /// Byte limits applied to temperature check drafts
+    pub draft_limits: DraftLimits,
+    /// Label of the abstain option appended after the draft's options, None to disable.
+    /// Abstain cannot be combined with other options.
+    pub abstain_option_label: Option<String>,
+    /// Power mode applied to newly created items
+    pub power_mode: PowerMode,
 }
 
-/// Struct used to hold submitted temperature check data
-#[derive(ScryptoSbor)]
-pub struct TemperatureCheck {
+/// Byte limits on the text fields of a temperature check draft
+#[derive(ScryptoSbor, ManifestSbor, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DraftLimits {
+    pub max_title_bytes: u32,
+    pub max_short_description_bytes: u32,
+    /// Maximum length in bytes of an inline description; larger ones must use a `ContentRef`
+    pub max_inline_description_bytes: u32,
+    pub max_option_label_bytes: u32,
+    pub max_option_description_bytes: u32,
+}
+
+/// Configuration of the M-of-N admin approval subsystem
+#[derive(ScryptoSbor, ManifestSbor, Clone, Debug)]
+pub struct MultisigConfig {
+    /// Non-fungible badge resource; each badge counts as one distinct admin
+    pub admin_badge: ResourceAddress,
+    /// Number of distinct admin badges required before an action executes
+    pub threshold: u32,
+    /// Number of days a proposed action stays open for approvals
+    pub action_validity_days: u16,
+}
+
+/// A privileged governance action that admins can propose and approve
+#[derive(ScryptoSbor, ManifestSbor, Clone, Debug)]
+pub enum GovernanceAction {
+    MakeProposal {
+        temperature_check_id: u64,
+        eligibility: Option<EligibilityRule>,
+    },
+    UpdateGovernanceParameters {
+        new_params: GovernanceParameters,
+    },
+    ToggleTemperatureCheckHidden {
+        temperature_check_id: u64,
+    },
+    ToggleProposalHidden {
+        proposal_id: u64,
+    },
+    CancelTemperatureCheck {
+        temperature_check_id: u64,
+        reason: String,
+    },
+    CancelProposal {
+        proposal_id: u64,
+        reason: String,
+    },
+    SetPowerSource {
+        source: PowerSource,
+    },
+    RemovePowerSource {
+        address: GlobalAddress,
+    },
+    ExtendTemperatureCheckDeadline {
+        temperature_check_id: u64,
+        minutes: u32,
+    },
+    ExtendProposalDeadline {
+        proposal_id: u64,
+        minutes: u32,
+    },
+    Pause {
+        flags: PauseFlags,
+    },
+    Unpause {
+        extend_temperature_check_ids: Vec<u64>,
+        extend_proposal_ids: Vec<u64>,
+    },
+    /// Replaces the badge that authorizes `post_power_root`
+    SetAttestorBadge {
+        badge: ResourceAddress,
+    },
+    /// Replaces the multisig config, or removes it to hand privileged actions back to the owner
+    SetMultisigConfig {
+        new_config: Option<MultisigConfig>,
+    },
+}
+
+/// Lifecycle status of a pending action
+#[derive(ScryptoSbor, ManifestSbor, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PendingActionStatus {
+    /// Waiting for approvals
+    Pending,
+    /// The threshold was reached, waiting for `execute_action`
+    Approved,
+    Executed,
+    Cancelled,
+    /// `expires_at` passed before the action was executed.
+    /// Derived from the clock when the action is read, never stored.
+    Expired,
+}
+
+/// A privileged action waiting for admin approvals
+#[derive(ScryptoSbor, Clone, Debug)]
+pub struct PendingAction {
+    pub action: GovernanceAction,
+    pub proposed_by: NonFungibleLocalId,
+    /// Admin badges that approved the action, the proposer's badge being the first
+    pub approvals: Vec<NonFungibleLocalId>,
+    /// Threshold of the multisig config at the time the action was proposed
+    pub threshold: u32,
+    pub proposed_at: Instant,
+    /// After this instant the action can no longer be approved or executed
+    pub expires_at: Instant,
+    pub status: PendingActionStatus,
+}
+
+/// Emergency pause flags, each one halting a separate part of the governance flow
+#[derive(ScryptoSbor, ManifestSbor, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PauseFlags {
+    /// Halts creation of temperature checks and proposals
+    pub creation: bool,
+    /// Halts voting on temperature checks
+    pub temperature_check_voting: bool,
+    /// Halts voting on proposals
+    pub proposal_voting: bool,
+}
+
+impl PauseFlags {
+    /// Returns true if any part of the governance flow is paused
+    pub fn any(&self) -> bool {
+        self.creation || self.temperature_check_voting || self.proposal_voting
+    }
+}
+
+/// Who stopped a temperature check or proposal
+#[derive(ScryptoSbor, Clone, Debug, PartialEq, Eq)]
+pub enum CancelledBy {
+    /// The author withdrew their temperature check before any votes were cast
+    Author(Global<Account>),
+    /// Cancelled directly by the owner
+    Owner,
+    /// Cancelled through an executed multisig action
+    Admins { action_id: u64 },
+}
+
+/// Record of a temperature check or proposal being stopped
+#[derive(ScryptoSbor, Clone, Debug)]
+pub struct Cancellation {
+    pub cancelled_by: CancelledBy,
+    /// Reason given for the cancellation, None for author withdrawals
+    pub reason: Option<String>,
+    pub cancelled_at: Instant,
+}
+
+/// Immutable content of a temperature check, shared by the proposal it is elevated to
+/// Stored in its own KVS entry so that voting does not load it
+#[derive(ScryptoSbor, Clone, Debug)]
+pub struct ItemContent {
     pub title: String,
     /// Short summary of the proposal
     pub short_description: String,
-    /// Full description in markdown format
-    pub description: String,
+    /// Full description in markdown format, inline or as an off-ledger reference
+    pub description: Description,
     pub vote_options: Vec<ProposalVoteOption>,
     /// External links related to the proposal
     pub links: Vec<Url>,
+    /// The RFC this temperature check is based on
+    pub rfc_reference: Option<RfcReference>,
+    /// Supporting documents
+    pub attachments: Vec<Attachment>,
+}
+
+/// Struct used to hold submitted temperature check data
+/// This is the compact header loaded on every vote; the content lives in `temperature_check_contents`
+#[derive(ScryptoSbor)]
+pub struct TemperatureCheck {
+    /// Number of vote options in the content; option IDs are 0..vote_option_count
+    pub vote_option_count: u32,
+    /// ID of the abstain option, if one was appended (always the last option)
+    pub abstain_option_id: Option<ProposalVoteOptionId>,
+    /// Current votes per side, adjusted on revote and retraction
+    pub tally: TemperatureCheckTally,
+    /// Power mode captured from the governance parameters at creation
+    pub power_mode: PowerMode,
+    /// Merkle root of the power snapshot, posted by the attestor in `MerkleSnapshot` mode
+    pub power_root: Option<Hash>,
+    /// Current voting power per side, adjusted on revote and retraction
+    pub power_tally: TemperatureCheckPowerTally,
     pub quorum: Decimal,
     /// Maximum number of options a voter can select in the proposal.
     /// If None, only one option can be selected (single choice).
     /// If Some(n), up to n options can be selected (multiple choice).
     pub max_selections: Option<u32>,
+    /// Minimum number of options a voter must select in a multiple-choice proposal.
+    /// If None, at least one option must be selected.
+    pub min_selections: Option<u32>,
+    /// Holding voters must prove alongside their account. If None, every account can vote.
+    pub eligibility: Option<EligibilityRule>,
     /// Maps voter accounts to their vote entry (for deduplication and single-call lookup)
     pub voters: KeyValueStore<Global<Account>, TemperatureCheckVoterEntry>,
+    /// Accounts that retracted their vote, with the number of vote changes they have made.
+    /// Keeps the revote policy enforced when such an account votes again.
+    pub retracted_voters: KeyValueStore<Global<Account>, u32>,
+    /// Vote-escrow positions that have backed a ballot, with the account they backed.
+    /// A position can only back one account per item.
+    pub position_voters: KeyValueStore<NonFungibleLocalId, Global<Account>>,
+    /// Eligibility badges that have been presented, with the account that presented them.
+    /// A badge can only be used by one account per item.
+    pub eligibility_voters: KeyValueStore<NonFungibleLocalId, Global<Account>>,
+    /// Amount of a fungible eligibility resource each account proved on its latest ballot
+    pub eligibility_amounts: KeyValueStore<Global<Account>, Decimal>,
     /// Maps sequential vote IDs to vote records (for enumeration)
     pub votes: KeyValueStore<u64, TemperatureCheckVoteRecord>,
-    /// Counter for votes, incremented with each new vote
+    /// Counter for votes, incremented with each new vote or retraction
     pub vote_count: u64,
-    /// Counter for revotes, so unique voters = vote_count - revote_count
+    /// Counter for revotes, so current voters = vote_count - revote_count - 2 * retraction_count
     pub revote_count: u64,
+    /// Counter for retractions
+    pub retraction_count: u64,
     pub approval_threshold: Decimal,
     pub start: Instant,
     pub deadline: Instant,
+    /// Epoch in which the item was created, anchoring its power snapshot to the ledger
+    pub created_epoch: Epoch,
+    /// Hash of the transaction that created the item
+    pub created_transaction_hash: Hash,
+    /// Total minutes the owner has extended the deadline by
+    pub deadline_extension_minutes: u32,
+    /// Anti-sniping rule captured from the governance parameters at creation
+    pub anti_sniping: Option<AntiSnipingRule>,
+    /// Total minutes the deadline was extended by the anti-sniping rule
+    pub anti_sniping_extension_minutes: u32,
+    /// Revote policy captured from the governance parameters at creation
+    pub revote_policy: RevotePolicy,
     pub elevated_proposal_id: Option<u64>,
     /// The account that created this temperature check
     pub author: Global<Account>,
     /// Whether this temperature check is hidden from the front-end
     pub hidden: bool,
+    /// Set when the temperature check is withdrawn or cancelled; it then rejects votes and elevation
+    pub cancellation: Option<Cancellation>,
 }
 
 /// Struct for a proposal (GP - Governance Proposal)
+/// This is the compact header loaded on every vote; the content is that of the temperature check,
+/// stored under `temperature_check_id` in `temperature_check_contents`
 #[derive(ScryptoSbor)]
 pub struct Proposal {
-    pub title: String,
-    /// Short summary of the proposal
-    pub short_description: String,
-    /// Full description in markdown format
-    pub description: String,
-    pub vote_options: Vec<ProposalVoteOption>,
-    /// External links related to the proposal
-    pub links: Vec<Url>,
+    /// Number of vote options in the content; option IDs are 0..vote_option_count
+    pub vote_option_count: u32,
+    /// ID of the abstain option, if one was appended (always the last option)
+    pub abstain_option_id: Option<ProposalVoteOptionId>,
+    /// Number of accounts currently selecting each option, indexed by option ID.
+    /// Adjusted on revote and retraction.
+    pub option_tallies: Vec<u64>,
+    /// Power mode captured from the governance parameters at elevation
+    pub power_mode: PowerMode,
+    /// Merkle root of the power snapshot, posted by the attestor in `MerkleSnapshot` mode
+    pub power_root: Option<Hash>,
+    /// Voting power currently behind each option, indexed by option ID.
+    /// A ballot's full power counts for every option it selects.
+    pub option_powers: Vec<Decimal>,
+    /// Voting power of the current ballots, each counted once
+    pub total_power: Decimal,
     pub quorum: Decimal,
     /// Maximum number of options a voter can select.
     /// If None, only one option can be selected (single choice).
     /// If Some(n), up to n options can be selected (multiple choice).
     pub max_selections: Option<u32>,
+    /// Minimum number of options a voter must select.
+    /// If None, at least one option must be selected.
+    pub min_selections: Option<u32>,
+    /// Holding voters must prove alongside their account. If None, every account can vote.
+    pub eligibility: Option<EligibilityRule>,
     /// Maps voter accounts to their vote entry (for deduplication and single-call lookup)
     pub voters: KeyValueStore<Global<Account>, ProposalVoterEntry>,
+    /// Accounts that retracted their vote, with the number of vote changes they have made.
+    /// Keeps the revote policy enforced when such an account votes again.
+    pub retracted_voters: KeyValueStore<Global<Account>, u32>,
+    /// Vote-escrow positions that have backed a ballot, with the account they backed.
+    /// A position can only back one account per item.
+    pub position_voters: KeyValueStore<NonFungibleLocalId, Global<Account>>,
+    /// Eligibility badges that have been presented, with the account that presented them.
+    /// A badge can only be used by one account per item.
+    pub eligibility_voters: KeyValueStore<NonFungibleLocalId, Global<Account>>,
+    /// Amount of a fungible eligibility resource each account proved on its latest ballot
+    pub eligibility_amounts: KeyValueStore<Global<Account>, Decimal>,
     /// Maps sequential vote IDs to vote records (for enumeration)
     pub votes: KeyValueStore<u64, ProposalVoteRecord>,
-    /// Counter for votes, incremented with each new vote
+    /// Counter for votes, incremented with each new vote or retraction
     pub vote_count: u64,
-    /// Counter for revotes, so unique voters = vote_count - revote_count
+    /// Counter for revotes, so current voters = vote_count - revote_count - 2 * retraction_count
     pub revote_count: u64,
+    /// Counter for retractions
+    pub retraction_count: u64,
     pub approval_threshold: Decimal,
     pub start: Instant,
     pub deadline: Instant,
+    /// Epoch in which the item was created, anchoring its power snapshot to the ledger
+    pub created_epoch: Epoch,
+    /// Hash of the transaction that created the item
+    pub created_transaction_hash: Hash,
+    /// Total minutes the owner has extended the deadline by
+    pub deadline_extension_minutes: u32,
+    /// Anti-sniping rule captured from the governance parameters at creation
+    pub anti_sniping: Option<AntiSnipingRule>,
+    /// Total minutes the deadline was extended by the anti-sniping rule
+    pub anti_sniping_extension_minutes: u32,
+    /// Revote policy captured from the governance parameters at creation
+    pub revote_policy: RevotePolicy,
     pub temperature_check_id: u64,
     /// The account that created the original temperature check
     pub author: Global<Account>,
     /// Whether this proposal is hidden from the front-end
     pub hidden: bool,
+    /// Set when the proposal is cancelled; it then rejects votes
+    pub cancellation: Option<Cancellation>,
+}
+
+// =============================================================================
+// View Types
+// =============================================================================
+
+/// Read-only snapshot of a temperature check, without KVS handles
+#[derive(ScryptoSbor, Clone, Debug)]
+pub struct TemperatureCheckView {
+    pub id: u64,
+    pub content: ItemContent,
+    pub quorum: Decimal,
+    pub max_selections: Option<u32>,
+    pub min_selections: Option<u32>,
+    pub eligibility: Option<EligibilityRule>,
+    pub abstain_option_id: Option<ProposalVoteOptionId>,
+    pub tally: TemperatureCheckTally,
+    pub power_mode: PowerMode,
+    pub power_root: Option<Hash>,
+    pub power_tally: TemperatureCheckPowerTally,
+    /// Power source registry as it was when the temperature check was created
+    pub power_sources: Vec<PowerSource>,
+    pub vote_count: u64,
+    pub revote_count: u64,
+    pub retraction_count: u64,
+    pub approval_threshold: Decimal,
+    pub start: Instant,
+    pub deadline: Instant,
+    pub created_epoch: Epoch,
+    pub created_transaction_hash: Hash,
+    pub deadline_extension_minutes: u32,
+    pub anti_sniping: Option<AntiSnipingRule>,
+    pub anti_sniping_extension_minutes: u32,
+    pub revote_policy: RevotePolicy,
+    pub elevated_proposal_id: Option<u64>,
+    pub author: Global<Account>,
+    pub hidden: bool,
+    pub cancellation: Option<Cancellation>,
+}
+
+/// Read-only snapshot of a proposal, without KVS handles
+/// The content is that of the temperature check the proposal was elevated from
+#[derive(ScryptoSbor, Clone, Debug)]
+pub struct ProposalView {
+    pub id: u64,
+    pub content: ItemContent,
+    pub quorum: Decimal,
+    pub max_selections: Option<u32>,
+    pub min_selections: Option<u32>,
+    pub eligibility: Option<EligibilityRule>,
+    pub abstain_option_id: Option<ProposalVoteOptionId>,
+    pub option_tallies: Vec<u64>,
+    pub power_mode: PowerMode,
+    pub power_root: Option<Hash>,
+    pub option_powers: Vec<Decimal>,
+    /// Power source registry as it was when the proposal was created
+    pub power_sources: Vec<PowerSource>,
+    pub vote_count: u64,
+    pub revote_count: u64,
+    pub retraction_count: u64,
+    pub approval_threshold: Decimal,
+    pub start: Instant,
+    pub deadline: Instant,
+    pub created_epoch: Epoch,
+    pub created_transaction_hash: Hash,
+    pub deadline_extension_minutes: u32,
+    pub anti_sniping: Option<AntiSnipingRule>,
+    pub anti_sniping_extension_minutes: u32,
+    pub revote_policy: RevotePolicy,
+    pub temperature_check_id: u64,
+    pub author: Global<Account>,
+    pub hidden: bool,
+    pub cancellation: Option<Cancellation>,
+}
+
+/// Weighted result of a temperature check, evaluated on-ledger
+#[derive(ScryptoSbor, Clone, Debug, PartialEq, Eq)]
+pub struct TemperatureCheckResult {
+    pub power_tally: TemperatureCheckPowerTally,
+    pub total_power: Decimal,
+    pub quorum_reached: bool,
+    /// Quorum reached and the For share of the total power meets the approval threshold
+    pub approved: bool,
+}
+
+/// Weighted result of a proposal, evaluated on-ledger
+#[derive(ScryptoSbor, Clone, Debug, PartialEq, Eq)]
+pub struct ProposalResult {
+    pub option_powers: Vec<Decimal>,
+    /// Power of the distinct ballots, each counted once regardless of its selections
+    pub total_power: Decimal,
+    pub quorum_reached: bool,
+    /// The non-abstain option with the most power, None if no option has any
+    pub leading_option: Option<ProposalVoteOptionId>,
+    /// Quorum reached and the leading option's share of the non-abstain power
+    /// meets the approval threshold
+    pub approved: bool,
+}
+
+/// A vote record of either item kind
+#[derive(ScryptoSbor, Clone, Debug)]
+pub enum VoteRecord {
+    TemperatureCheck(TemperatureCheckVoteRecord),
+    Proposal(ProposalVoteRecord),
+}
+
+/// A vote record together with its sequential ID
+#[derive(ScryptoSbor, Clone, Debug)]
+pub struct VoteView {
+    pub vote_id: u64,
+    pub record: VoteRecord,
 }
 
 // =============================================================================
@@ -204,8 +992,15 @@ pub struct Delegation {
 pub struct TemperatureCheckCreatedEvent {
     pub temperature_check_id: u64,
     pub title: String,
+    pub rfc_reference: Option<RfcReference>,
+    pub attachments: Vec<Attachment>,
+    pub vote_options: Vec<ProposalVoteOption>,
+    pub power_sources: Vec<PowerSource>,
     pub start: Instant,
     pub deadline: Instant,
+    pub created_epoch: Epoch,
+    pub created_transaction_hash: Hash,
+    pub eligibility: Option<EligibilityRule>,
 }
 
 /// Emitted when a vote is cast on a temperature check
@@ -217,6 +1012,19 @@ pub struct TemperatureCheckVotedEvent {
     pub vote: TemperatureCheckVote,
     /// If this vote replaces a previous vote, this is the ID of the replaced vote
     pub replacing_vote_id: Option<u64>,
+    pub rationale: Option<VoteRationale>,
+    pub power: Decimal,
+    pub position_ids: Vec<NonFungibleLocalId>,
+}
+
+/// Emitted when an account retracts its vote on a temperature check
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct TemperatureCheckVoteRetractedEvent {
+    pub temperature_check_id: u64,
+    /// ID of the retraction record in the votes KVS
+    pub vote_id: u64,
+    pub account: Global<Account>,
+    pub retracted_vote_id: u64,
 }
 
 /// Emitted when a temperature check is elevated to a proposal
@@ -225,8 +1033,13 @@ pub struct ProposalCreatedEvent {
     pub proposal_id: u64,
     pub temperature_check_id: u64,
     pub title: String,
+    pub vote_options: Vec<ProposalVoteOption>,
+    pub power_sources: Vec<PowerSource>,
     pub start: Instant,
     pub deadline: Instant,
+    pub created_epoch: Epoch,
+    pub created_transaction_hash: Hash,
+    pub eligibility: Option<EligibilityRule>,
 }
 
 /// Emitted when a vote is cast on a proposal
@@ -238,6 +1051,45 @@ pub struct ProposalVotedEvent {
     pub options: Vec<ProposalVoteOptionId>,
     /// If this vote replaces a previous vote, this is the ID of the replaced vote
     pub replacing_vote_id: Option<u64>,
+    pub rationale: Option<VoteRationale>,
+    pub power: Decimal,
+    pub position_ids: Vec<NonFungibleLocalId>,
+}
+
+/// Emitted when an account retracts its vote on a proposal
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct ProposalVoteRetractedEvent {
+    pub proposal_id: u64,
+    /// ID of the retraction record in the votes KVS
+    pub vote_id: u64,
+    pub account: Global<Account>,
+    pub retracted_vote_id: u64,
+}
+
+/// Emitted when a temperature check is withdrawn by its author or cancelled
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct TemperatureCheckCancelledEvent {
+    pub temperature_check_id: u64,
+    pub cancelled_by: CancelledBy,
+    pub reason: Option<String>,
+}
+
+/// Emitted when a proposal is cancelled
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct ProposalCancelledEvent {
+    pub proposal_id: u64,
+    pub cancelled_by: CancelledBy,
+    pub reason: Option<String>,
+}
+
+/// Emitted when the deadline of a temperature check or proposal is moved out
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct DeadlineExtendedEvent {
+    pub item_kind: ItemKind,
+    pub item_id: u64,
+    pub previous_deadline: Instant,
+    pub new_deadline: Instant,
+    pub reason: DeadlineExtensionReason,
 }
 
 /// Emitted when governance parameters are updated
@@ -246,6 +1098,109 @@ pub struct GovernanceParametersUpdatedEvent {
     pub new_params: GovernanceParameters,
 }
 
+/// Emitted when the owner pauses (part of) the governance flow
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct PausedEvent {
+    pub flags: PauseFlags,
+    /// Start of the pause, kept from the first call if the pause flags are changed later
+    pub paused_at: Instant,
+}
+
+/// Emitted when the owner lifts the pause
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct UnpausedEvent {
+    pub paused_at: Instant,
+    /// When temperature check voting was paused, None if it was not paused at the end
+    pub temperature_check_voting_paused_at: Option<Instant>,
+    /// When proposal voting was paused, None if it was not paused at the end
+    pub proposal_voting_paused_at: Option<Instant>,
+    pub unpaused_at: Instant,
+    /// Temperature checks whose deadline was moved out by the paused duration
+    pub extended_temperature_check_ids: Vec<u64>,
+    /// Proposals whose deadline was moved out by the paused duration
+    pub extended_proposal_ids: Vec<u64>,
+}
+
+/// Emitted when the multisig config is set or removed
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct MultisigConfigUpdatedEvent {
+    pub new_config: Option<MultisigConfig>,
+}
+
+/// Emitted when an admin proposes a privileged action
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct ActionProposedEvent {
+    pub action_id: u64,
+    pub action: GovernanceAction,
+    pub proposed_by: NonFungibleLocalId,
+    pub threshold: u32,
+    pub expires_at: Instant,
+}
+
+/// Emitted when an admin approves a pending action (including the proposer's implicit approval)
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct ActionApprovedEvent {
+    pub action_id: u64,
+    pub approved_by: NonFungibleLocalId,
+    pub approval_count: u32,
+}
+
+/// Emitted when an approved action is executed
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct ActionExecutedEvent {
+    pub action_id: u64,
+    pub action: GovernanceAction,
+}
+
+/// Emitted when the proposer cancels a pending action
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct ActionCancelledEvent {
+    pub action_id: u64,
+}
+
+/// Emitted when the badge that authorizes the attestor is replaced
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct AttestorUpdatedEvent {
+    pub badge: ResourceAddress,
+}
+
+/// Emitted when the attestor posts the power snapshot root of an item
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct PowerRootPostedEvent {
+    pub item_kind: ItemKind,
+    pub item_id: u64,
+    pub root: Hash,
+}
+
+/// Emitted when a power source is added to the registry or its entry replaced
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct PowerSourceSetEvent {
+    pub source: PowerSource,
+}
+
+/// Emitted when a power source is removed from the registry
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct PowerSourceRemovedEvent {
+    pub address: GlobalAddress,
+}
+
+/// Emitted when tokens are locked into a new vote-escrow position
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct EscrowPositionLockedEvent {
+    pub position_id: NonFungibleLocalId,
+    pub locked_resource: ResourceAddress,
+    pub amount: Decimal,
+    pub unlock_at: Instant,
+}
+
+/// Emitted when a vote-escrow position is burned and its tokens returned
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct EscrowPositionWithdrawnEvent {
+    pub position_id: NonFungibleLocalId,
+    pub locked_resource: ResourceAddress,
+    pub amount: Decimal,
+}
+
 /// Emitted when a delegation is created or updated
 #[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
 pub struct DelegationCreatedEvent {
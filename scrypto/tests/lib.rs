@@ -38,12 +38,36 @@ fn create_owner_badge_with_account(
 
 fn create_governance_parameters() -> GovernanceParameters {
     GovernanceParameters {
-        temperature_check_days: 7,
+        temperature_check_length_minutes: 7 * 24 * 60,
         temperature_check_quorum: dec!(1000),
         temperature_check_approval_threshold: dec!("0.5"),
-        proposal_length_days: 14,
+        proposal_length_minutes: 14 * 24 * 60,
         proposal_quorum: dec!(5000),
         proposal_approval_threshold: dec!("0.5"),
+        max_start_delay_minutes: 7 * 24 * 60,
+        max_deadline_extension_minutes: 3 * 24 * 60,
+        anti_sniping: None,
+        revote_policy: RevotePolicy::NoRevotes,
+        max_rationale_length: 280,
+        draft_limits: DraftLimits {
+            max_title_bytes: 200,
+            max_short_description_bytes: 500,
+            max_inline_description_bytes: 64 * 1024,
+            max_option_label_bytes: 100,
+            max_option_description_bytes: 500,
+        },
+        abstain_option_label: None,
+        power_mode: PowerMode::Unweighted,
+    }
+}
+
+/// Returns a vote option input with only a label
+fn vote_option(label: &str) -> ProposalVoteOptionInput {
+    ProposalVoteOptionInput {
+        label: label.to_string(),
+        description: None,
+        color: None,
+        link: None,
     }
 }
 
@@ -51,17 +75,20 @@ fn create_temp_check_draft() -> TemperatureCheckDraft {
     TemperatureCheckDraft {
         title: "Test Proposal".to_string(),
         short_description: "A short summary of the test proposal".to_string(),
-        description: "# Test Proposal\n\nA full markdown description of the test proposal.".to_string(),
+        description: Description::Inline(
+            "# Test Proposal\n\nA full markdown description of the test proposal.".to_string(),
+        ),
         vote_options: vec![
-            ProposalVoteOptionInput {
-                label: "For".to_string(),
-            },
-            ProposalVoteOptionInput {
-                label: "Against".to_string(),
-            },
+            vote_option("For"),
+            vote_option("Against"),
         ],
         links: vec![Url::of("https://radixtalk.com/proposal/123")],
+        rfc_reference: None,
+        attachments: vec![],
         max_selections: None, // Single choice
+        min_selections: None,
+        eligibility: None,
+        start: None,
     }
 }
 
@@ -69,23 +96,100 @@ fn create_multi_choice_temp_check_draft() -> TemperatureCheckDraft {
     TemperatureCheckDraft {
         title: "Multi-Choice Test Proposal".to_string(),
         short_description: "A short summary of the multi-choice proposal".to_string(),
-        description: "# Multi-Choice Proposal\n\nA full markdown description with multiple choice voting.".to_string(),
+        description: Description::Inline(
+            "# Multi-Choice Proposal\n\nA full markdown description with multiple choice voting."
+                .to_string(),
+        ),
         vote_options: vec![
-            ProposalVoteOptionInput {
-                label: "Option A".to_string(),
-            },
-            ProposalVoteOptionInput {
-                label: "Option B".to_string(),
-            },
-            ProposalVoteOptionInput {
-                label: "Option C".to_string(),
-            },
+            vote_option("Option A"),
+            vote_option("Option B"),
+            vote_option("Option C"),
         ],
         links: vec![Url::of("https://radixtalk.com/proposal/456")],
+        rfc_reference: None,
+        attachments: vec![],
         max_selections: Some(2), // Can select up to 2 options
+        min_selections: None,
+        eligibility: None,
+        start: None,
     }
 }
 
+/// Publishes the package and instantiates a governance component
+/// Returns the governance component address
+fn instantiate_governance(
+    ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>,
+    owner_badge: ResourceAddress,
+    params: GovernanceParameters,
+) -> ComponentAddress {
+    let package_address = ledger.compile_and_publish(this_package!());
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "Governance",
+            "instantiate",
+            manifest_args!(owner_badge, params),
+        )
+        .build();
+
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    receipt.expect_commit(true).new_component_addresses()[0]
+}
+
+/// Creates a temperature check authored by the given account
+fn make_temperature_check(
+    ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>,
+    governance_component: ComponentAddress,
+    author_account: ComponentAddress,
+    author_pk: &Secp256k1PublicKey,
+    draft: TemperatureCheckDraft,
+) {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "make_temperature_check",
+            manifest_args!(author_account, draft),
+        )
+        .build();
+
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(author_pk)],
+        )
+        .expect_commit_success();
+}
+
+/// Builds a manifest calling an admin method with a proof of a single admin badge
+fn admin_badge_manifest<F, A>(
+    admin_account: ComponentAddress,
+    admin_badge: ResourceAddress,
+    badge_id: u64,
+    governance_component: ComponentAddress,
+    method_name: &str,
+    args: F,
+) -> TransactionManifestV1
+where
+    F: FnOnce(ManifestProof) -> A,
+    A: ResolvableArguments,
+{
+    ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_non_fungibles(
+            admin_account,
+            admin_badge,
+            [NonFungibleLocalId::integer(badge_id)],
+        )
+        .pop_from_auth_zone("admin_proof")
+        .call_method_with_name_lookup(governance_component, method_name, |lookup| {
+            args(lookup.proof("admin_proof"))
+        })
+        .build()
+}
+
 // =============================================================================
 // Governance Blueprint Tests
 // =============================================================================
@@ -110,6 +214,86 @@ fn test_governance_instantiate() {
     receipt.expect_commit_success();
 }
 
+#[test]
+fn test_governance_parameters_are_validated() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (owner_badge, owner_account, owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let package_address = ledger.compile_and_publish(this_package!());
+
+    // A zero-length voting period is rejected on instantiation
+    let mut params = create_governance_parameters();
+    params.temperature_check_length_minutes = 0;
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "Governance",
+            "instantiate",
+            manifest_args!(owner_badge, params),
+        )
+        .build();
+    ledger
+        .execute_manifest(manifest, vec![])
+        .expect_specific_failure(|e| {
+            format!("{:?}", e).contains("Temperature check length must be at least one minute")
+        });
+
+    // And on update, like every other invalid parameter
+    let governance_component =
+        instantiate_governance(&mut ledger, owner_badge, create_governance_parameters());
+    let defaults = create_governance_parameters();
+    let invalid_updates = vec![
+        (
+            GovernanceParameters {
+                proposal_length_minutes: 0,
+                ..defaults.clone()
+            },
+            "Proposal length must be at least one minute",
+        ),
+        (
+            GovernanceParameters {
+                max_deadline_extension_minutes: 0,
+                ..defaults.clone()
+            },
+            "Maximum deadline extension must be at least one minute",
+        ),
+        (
+            GovernanceParameters {
+                proposal_approval_threshold: dec!("1.5"),
+                ..defaults.clone()
+            },
+            "Approval threshold must be above 0 and at most 1",
+        ),
+        (
+            GovernanceParameters {
+                draft_limits: DraftLimits {
+                    max_title_bytes: 0,
+                    ..defaults.draft_limits
+                },
+                ..defaults
+            },
+            "Draft limits must be positive",
+        ),
+    ];
+    for (params, message) in invalid_updates {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_amount(owner_account, owner_badge, dec!(1))
+            .call_method(
+                governance_component,
+                "update_governance_parameters",
+                manifest_args!(params),
+            )
+            .build();
+        ledger
+            .execute_manifest(
+                manifest,
+                vec![NonFungibleGlobalId::from_public_key(&owner_pk)],
+            )
+            .expect_specific_failure(|e| format!("{:?}", e).contains(message));
+    }
+}
+
 #[test]
 fn test_make_temperature_check() {
     let mut ledger = LedgerSimulatorBuilder::new().build();
@@ -218,7 +402,13 @@ fn test_vote_on_temperature_check() {
         .call_method(
             governance_component,
             "vote_on_temperature_check",
-            manifest_args!(account, 0u64, TemperatureCheckVote::For),
+            manifest_args!(
+                account,
+                0u64,
+                TemperatureCheckVote::For,
+                None::<VoteRationale>,
+                None::<ManifestProof>
+            ),
         )
         .build();
 
@@ -280,7 +470,13 @@ fn test_cannot_vote_twice_on_temperature_check() {
         .call_method(
             governance_component,
             "vote_on_temperature_check",
-            manifest_args!(account, 0u64, TemperatureCheckVote::For),
+            manifest_args!(
+                account,
+                0u64,
+                TemperatureCheckVote::For,
+                None::<VoteRationale>,
+                None::<ManifestProof>
+            ),
         )
         .build();
 
@@ -297,7 +493,13 @@ fn test_cannot_vote_twice_on_temperature_check() {
         .call_method(
             governance_component,
             "vote_on_temperature_check",
-            manifest_args!(account, 0u64, TemperatureCheckVote::Against),
+            manifest_args!(
+                account,
+                0u64,
+                TemperatureCheckVote::Against,
+                None::<VoteRationale>,
+                None::<ManifestProof>
+            ),
         )
         .build();
 
@@ -359,7 +561,7 @@ fn test_make_proposal_from_temperature_check() {
         .call_method(
             governance_component,
             "make_proposal",
-            manifest_args!(0u64),
+            manifest_args!(0u64, None::<EligibilityRule>),
         )
         .build();
 
@@ -698,7 +900,7 @@ fn test_multi_choice_proposal_voting() {
         .call_method(
             governance_component,
             "make_proposal",
-            manifest_args!(0u64),
+            manifest_args!(0u64, None::<EligibilityRule>),
         )
         .build();
 
@@ -716,7 +918,13 @@ fn test_multi_choice_proposal_voting() {
         .call_method(
             governance_component,
             "vote_on_proposal",
-            manifest_args!(voter_account, 0u64, votes),
+            manifest_args!(
+                voter_account,
+                0u64,
+                votes,
+                None::<VoteRationale>,
+                None::<ManifestProof>
+            ),
         )
         .build();
 
@@ -779,7 +987,7 @@ fn test_multi_choice_exceeds_max_selections() {
         .call_method(
             governance_component,
             "make_proposal",
-            manifest_args!(0u64),
+            manifest_args!(0u64, None::<EligibilityRule>),
         )
         .build();
 
@@ -801,7 +1009,13 @@ fn test_multi_choice_exceeds_max_selections() {
         .call_method(
             governance_component,
             "vote_on_proposal",
-            manifest_args!(voter_account, 0u64, votes),
+            manifest_args!(
+                voter_account,
+                0u64,
+                votes,
+                None::<VoteRationale>,
+                None::<ManifestProof>
+            ),
         )
         .build();
 
@@ -864,7 +1078,7 @@ fn test_single_choice_requires_exactly_one_vote() {
         .call_method(
             governance_component,
             "make_proposal",
-            manifest_args!(0u64),
+            manifest_args!(0u64, None::<EligibilityRule>),
         )
         .build();
 
@@ -882,7 +1096,13 @@ fn test_single_choice_requires_exactly_one_vote() {
         .call_method(
             governance_component,
             "vote_on_proposal",
-            manifest_args!(voter_account, 0u64, votes),
+            manifest_args!(
+                voter_account,
+                0u64,
+                votes,
+                None::<VoteRationale>,
+                None::<ManifestProof>
+            ),
         )
         .build();
 
@@ -955,3 +1175,4714 @@ fn test_delegation_minimum_fraction() {
     );
     receipt.expect_commit_success();
 }
+
+// =============================================================================
+// Multisig Approval Tests
+// =============================================================================
+
+/// Sets up governance with a 2-of-3 multisig and one temperature check
+/// Returns (ledger, governance_component, admin_account, admin_pk, admin_badge)
+fn setup_multisig_governance() -> (
+    LedgerSimulator<NoExtension, InMemorySubstateDatabase>,
+    ComponentAddress,
+    ComponentAddress,
+    Secp256k1PublicKey,
+    ResourceAddress,
+) {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (owner_badge, owner_account, owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let governance_component =
+        instantiate_governance(&mut ledger, owner_badge, create_governance_parameters());
+
+    // Admin account holding admin badges #1#, #2# and #3#
+    let (admin_pk, _admin_sk, admin_account) = ledger.new_allocated_account();
+    let admin_badge = ledger.create_non_fungible_resource(admin_account);
+
+    let config = MultisigConfig {
+        admin_badge,
+        threshold: 2,
+        action_validity_days: 3,
+    };
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(owner_account, owner_badge, dec!(1))
+        .call_method(
+            governance_component,
+            "set_multisig_config",
+            manifest_args!(Some(config)),
+        )
+        .build();
+
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&owner_pk)],
+        )
+        .expect_commit_success();
+
+    let (author_pk, _author_sk, author_account) = ledger.new_allocated_account();
+    make_temperature_check(
+        &mut ledger,
+        governance_component,
+        author_account,
+        &author_pk,
+        create_temp_check_draft(),
+    );
+
+    (ledger, governance_component, admin_account, admin_pk, admin_badge)
+}
+
+#[test]
+fn test_multisig_action_executes_at_threshold() {
+    let (mut ledger, governance_component, admin_account, admin_pk, admin_badge) =
+        setup_multisig_governance();
+
+    // Propose elevating the temperature check (first approval)
+    let manifest = admin_badge_manifest(
+        admin_account,
+        admin_badge,
+        1,
+        governance_component,
+        "propose_action",
+        |proof| {
+            (
+                proof,
+                GovernanceAction::MakeProposal {
+                    temperature_check_id: 0,
+                    eligibility: None,
+                },
+            )
+        },
+    );
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&admin_pk)],
+        )
+        .expect_commit_success();
+
+    // Not executed yet
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "get_proposal_count", manifest_args!())
+        .build();
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let count: u64 = receipt.expect_commit_success().output(1);
+    assert_eq!(count, 0);
+
+    // The same badge cannot approve twice
+    let manifest = admin_badge_manifest(
+        admin_account,
+        admin_badge,
+        1,
+        governance_component,
+        "approve_action",
+        |proof| (proof, 0u64),
+    );
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&admin_pk)],
+        )
+        .expect_commit_failure();
+
+    // A second badge reaches the threshold, which approves the action
+    let manifest = admin_badge_manifest(
+        admin_account,
+        admin_badge,
+        2,
+        governance_component,
+        "approve_action",
+        |proof| (proof, 0u64),
+    );
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&admin_pk)],
+        )
+        .expect_commit_success();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "get_proposal_count", manifest_args!())
+        .call_method(governance_component, "get_pending_action", manifest_args!(0u64))
+        .build();
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let commit = receipt.expect_commit_success();
+    let count: u64 = commit.output(1);
+    assert_eq!(count, 0);
+    let pending: Option<PendingAction> = commit.output(2);
+    assert_eq!(pending.unwrap().status, PendingActionStatus::Approved);
+
+    // Any admin badge can execute the approved action
+    let manifest = admin_badge_manifest(
+        admin_account,
+        admin_badge,
+        3,
+        governance_component,
+        "execute_action",
+        |proof| (proof, 0u64),
+    );
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&admin_pk)],
+        )
+        .expect_commit_success();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "get_proposal_count", manifest_args!())
+        .call_method(governance_component, "get_pending_action", manifest_args!(0u64))
+        .build();
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let commit = receipt.expect_commit_success();
+    let count: u64 = commit.output(1);
+    assert_eq!(count, 1);
+    let pending: Option<PendingAction> = commit.output(2);
+    let pending = pending.unwrap();
+    assert_eq!(pending.status, PendingActionStatus::Executed);
+    assert_eq!(pending.approvals.len(), 2);
+
+    // Executed actions cannot be approved or executed again
+    for method in ["approve_action", "execute_action"] {
+        let manifest = admin_badge_manifest(
+            admin_account,
+            admin_badge,
+            3,
+            governance_component,
+            method,
+            |proof| (proof, 0u64),
+        );
+        ledger
+            .execute_manifest(
+                manifest,
+                vec![NonFungibleGlobalId::from_public_key(&admin_pk)],
+            )
+            .expect_specific_failure(|e| {
+                format!("{:?}", e).contains("Action is no longer pending")
+            });
+    }
+}
+
+#[test]
+fn test_multisig_failed_execution_keeps_action_approved() {
+    let (mut ledger, governance_component, admin_account, admin_pk, admin_badge) =
+        setup_multisig_governance();
+
+    // An extension beyond the three day maximum is approved but cannot be executed
+    let manifest = admin_badge_manifest(
+        admin_account,
+        admin_badge,
+        1,
+        governance_component,
+        "propose_action",
+        |proof| {
+            (
+                proof,
+                GovernanceAction::ExtendTemperatureCheckDeadline {
+                    temperature_check_id: 0,
+                    minutes: 4 * 24 * 60,
+                },
+            )
+        },
+    );
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&admin_pk)],
+        )
+        .expect_commit_success();
+
+    // Executing before the threshold is reached fails
+    let manifest = admin_badge_manifest(
+        admin_account,
+        admin_badge,
+        1,
+        governance_component,
+        "execute_action",
+        |proof| (proof, 0u64),
+    );
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&admin_pk)],
+        )
+        .expect_specific_failure(|e| {
+            format!("{:?}", e).contains("Action has not reached its threshold")
+        });
+
+    let manifest = admin_badge_manifest(
+        admin_account,
+        admin_badge,
+        2,
+        governance_component,
+        "approve_action",
+        |proof| (proof, 0u64),
+    );
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&admin_pk)],
+        )
+        .expect_commit_success();
+
+    let manifest = admin_badge_manifest(
+        admin_account,
+        admin_badge,
+        2,
+        governance_component,
+        "execute_action",
+        |proof| (proof, 0u64),
+    );
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&admin_pk)],
+        )
+        .expect_specific_failure(|e| {
+            format!("{:?}", e).contains("Deadline cannot be extended by more than")
+        });
+
+    // The approvals are kept and the proposer can still cancel the action
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "get_pending_action", manifest_args!(0u64))
+        .build();
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let pending: Option<PendingAction> = receipt.expect_commit_success().output(1);
+    let pending = pending.unwrap();
+    assert_eq!(pending.status, PendingActionStatus::Approved);
+    assert_eq!(pending.approvals.len(), 2);
+
+    let manifest = admin_badge_manifest(
+        admin_account,
+        admin_badge,
+        1,
+        governance_component,
+        "cancel_action",
+        |proof| (proof, 0u64),
+    );
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&admin_pk)],
+        )
+        .expect_commit_success();
+}
+
+#[test]
+fn test_multisig_cancelled_action_cannot_be_approved() {
+    let (mut ledger, governance_component, admin_account, admin_pk, admin_badge) =
+        setup_multisig_governance();
+
+    let manifest = admin_badge_manifest(
+        admin_account,
+        admin_badge,
+        1,
+        governance_component,
+        "propose_action",
+        |proof| {
+            (
+                proof,
+                GovernanceAction::ToggleTemperatureCheckHidden {
+                    temperature_check_id: 0,
+                },
+            )
+        },
+    );
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&admin_pk)],
+        )
+        .expect_commit_success();
+
+    // Only the proposer can cancel
+    let manifest = admin_badge_manifest(
+        admin_account,
+        admin_badge,
+        2,
+        governance_component,
+        "cancel_action",
+        |proof| (proof, 0u64),
+    );
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&admin_pk)],
+        )
+        .expect_commit_failure();
+
+    let manifest = admin_badge_manifest(
+        admin_account,
+        admin_badge,
+        1,
+        governance_component,
+        "cancel_action",
+        |proof| (proof, 0u64),
+    );
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&admin_pk)],
+        )
+        .expect_commit_success();
+
+    let manifest = admin_badge_manifest(
+        admin_account,
+        admin_badge,
+        2,
+        governance_component,
+        "approve_action",
+        |proof| (proof, 0u64),
+    );
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&admin_pk)],
+        )
+        .expect_commit_failure();
+}
+
+#[test]
+fn test_multisig_action_expires() {
+    let (mut ledger, governance_component, admin_account, admin_pk, admin_badge) =
+        setup_multisig_governance();
+
+    let manifest = admin_badge_manifest(
+        admin_account,
+        admin_badge,
+        1,
+        governance_component,
+        "propose_action",
+        |proof| {
+            (
+                proof,
+                GovernanceAction::ToggleTemperatureCheckHidden {
+                    temperature_check_id: 0,
+                },
+            )
+        },
+    );
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&admin_pk)],
+        )
+        .expect_commit_success();
+
+    // Move past the three day validity
+    let now = ledger.get_current_time(TimePrecision::Second);
+    ledger.set_current_time(now.add_days(4).unwrap().seconds_since_unix_epoch * 1000);
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "get_pending_action", manifest_args!(0u64))
+        .build();
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let pending: Option<PendingAction> = receipt.expect_commit_success().output(1);
+    assert_eq!(pending.unwrap().status, PendingActionStatus::Expired);
+
+    for method in ["approve_action", "cancel_action"] {
+        let badge_id = if method == "approve_action" { 2 } else { 1 };
+        let manifest = admin_badge_manifest(
+            admin_account,
+            admin_badge,
+            badge_id,
+            governance_component,
+            method,
+            |proof| (proof, 0u64),
+        );
+        ledger
+            .execute_manifest(
+                manifest,
+                vec![NonFungibleGlobalId::from_public_key(&admin_pk)],
+            )
+            .expect_specific_failure(|e| format!("{:?}", e).contains("Action has expired"));
+    }
+}
+
+#[test]
+fn test_multisig_replaces_owner_powers() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (owner_badge, owner_account, owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let governance_component =
+        instantiate_governance(&mut ledger, owner_badge, create_governance_parameters());
+
+    let (admin_pk, _admin_sk, admin_account) = ledger.new_allocated_account();
+    let admin_badge = ledger.create_non_fungible_resource(admin_account);
+
+    let (author_pk, _author_sk, author_account) = ledger.new_allocated_account();
+    make_temperature_check(
+        &mut ledger,
+        governance_component,
+        author_account,
+        &author_pk,
+        create_temp_check_draft(),
+    );
+
+    let config = MultisigConfig {
+        admin_badge,
+        threshold: 1,
+        action_validity_days: 3,
+    };
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(owner_account, owner_badge, dec!(1))
+        .call_method(
+            governance_component,
+            "set_multisig_config",
+            manifest_args!(Some(config)),
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&owner_pk)],
+        )
+        .expect_commit_success();
+
+    // The owner can neither act directly nor take the config back
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(owner_account, owner_badge, dec!(1))
+        .call_method(governance_component, "make_proposal", manifest_args!(0u64, None::<EligibilityRule>))
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&owner_pk)],
+        )
+        .expect_specific_failure(|e| {
+            format!("{:?}", e).contains("Multisig approvals are configured, propose this action instead")
+        });
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(owner_account, owner_badge, dec!(1))
+        .call_method(
+            governance_component,
+            "set_multisig_config",
+            manifest_args!(None::<MultisigConfig>),
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&owner_pk)],
+        )
+        .expect_specific_failure(|e| {
+            format!("{:?}", e).contains("Multisig approvals are configured, propose this action instead")
+        });
+
+    // Emergency and attestor powers go through actions too
+    let flags = PauseFlags {
+        creation: true,
+        temperature_check_voting: false,
+        proposal_voting: false,
+    };
+    let owner_calls: Vec<(&str, ManifestArgs)> = vec![
+        ("pause", manifest_args!(flags)),
+        ("unpause", manifest_args!(Vec::<u64>::new(), Vec::<u64>::new())),
+        ("extend_temperature_check_deadline", manifest_args!(0u64, 60u32)),
+        ("set_attestor_badge", manifest_args!(admin_badge)),
+    ];
+    for (method, args) in owner_calls {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_amount(owner_account, owner_badge, dec!(1))
+            .call_method(governance_component, method, args)
+            .build();
+        ledger
+            .execute_manifest(
+                manifest,
+                vec![NonFungibleGlobalId::from_public_key(&owner_pk)],
+            )
+            .expect_specific_failure(|e| {
+                format!("{:?}", e)
+                    .contains("Multisig approvals are configured, propose this action instead")
+            });
+    }
+
+    let manifest = admin_badge_manifest(
+        admin_account,
+        admin_badge,
+        1,
+        governance_component,
+        "propose_action",
+        |proof| (proof, GovernanceAction::Pause { flags }),
+    );
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&admin_pk)],
+        )
+        .expect_commit_success();
+    let manifest = admin_badge_manifest(
+        admin_account,
+        admin_badge,
+        1,
+        governance_component,
+        "execute_action",
+        |proof| (proof, 0u64),
+    );
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&admin_pk)],
+        )
+        .expect_commit_success();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "get_pause_flags", manifest_args!())
+        .build();
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let paused: PauseFlags = receipt.expect_commit_success().output(1);
+    assert_eq!(paused, flags);
+
+    // The admins lift the pause and hand control back to the owner
+    let actions = vec![
+        GovernanceAction::Unpause {
+            extend_temperature_check_ids: Vec::new(),
+            extend_proposal_ids: Vec::new(),
+        },
+        GovernanceAction::SetMultisigConfig { new_config: None },
+    ];
+    for (action_id, action) in actions.into_iter().enumerate() {
+        let manifest = admin_badge_manifest(
+            admin_account,
+            admin_badge,
+            1,
+            governance_component,
+            "propose_action",
+            |proof| (proof, action),
+        );
+        ledger
+            .execute_manifest(
+                manifest,
+                vec![NonFungibleGlobalId::from_public_key(&admin_pk)],
+            )
+            .expect_commit_success();
+        let manifest = admin_badge_manifest(
+            admin_account,
+            admin_badge,
+            1,
+            governance_component,
+            "execute_action",
+            |proof| (proof, action_id as u64 + 1),
+        );
+        ledger
+            .execute_manifest(
+                manifest,
+                vec![NonFungibleGlobalId::from_public_key(&admin_pk)],
+            )
+            .expect_commit_success();
+    }
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(owner_account, owner_badge, dec!(1))
+        .call_method(governance_component, "make_proposal", manifest_args!(0u64, None::<EligibilityRule>))
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&owner_pk)],
+        )
+        .expect_commit_success();
+}
+
+// =============================================================================
+// Emergency Pause Tests
+// =============================================================================
+
+#[test]
+fn test_pause_blocks_voting_until_unpaused() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (owner_badge, owner_account, owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let governance_component =
+        instantiate_governance(&mut ledger, owner_badge, create_governance_parameters());
+
+    let (author_pk, _author_sk, author_account) = ledger.new_allocated_account();
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+
+    make_temperature_check(
+        &mut ledger,
+        governance_component,
+        author_account,
+        &author_pk,
+        create_temp_check_draft(),
+    );
+
+    // Pause temperature check voting
+    let flags = PauseFlags {
+        creation: false,
+        temperature_check_voting: true,
+        proposal_voting: false,
+    };
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(owner_account, owner_badge, dec!(1))
+        .call_method(governance_component, "pause", manifest_args!(flags))
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&owner_pk)],
+        )
+        .expect_commit_success();
+
+    // Voting fails while paused
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "vote_on_temperature_check",
+            manifest_args!(
+                voter_account,
+                0u64,
+                TemperatureCheckVote::For,
+                None::<VoteRationale>,
+                None::<ManifestProof>
+            ),
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&voter_pk)],
+        )
+        .expect_commit_failure();
+
+    // Creation is not paused
+    make_temperature_check(
+        &mut ledger,
+        governance_component,
+        author_account,
+        &author_pk,
+        create_temp_check_draft(),
+    );
+
+    // Unpause and extend the first temperature check
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(owner_account, owner_badge, dec!(1))
+        .call_method(
+            governance_component,
+            "unpause",
+            manifest_args!(vec![0u64], Vec::<u64>::new()),
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&owner_pk)],
+        )
+        .expect_commit_success();
+
+    // Voting works again
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "vote_on_temperature_check",
+            manifest_args!(
+                voter_account,
+                0u64,
+                TemperatureCheckVote::For,
+                None::<VoteRationale>,
+                None::<ManifestProof>
+            ),
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&voter_pk)],
+        )
+        .expect_commit_success();
+}
+
+#[test]
+fn test_creation_only_pause_does_not_extend_deadlines() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (owner_badge, owner_account, owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let governance_component =
+        instantiate_governance(&mut ledger, owner_badge, create_governance_parameters());
+
+    let (author_pk, _author_sk, author_account) = ledger.new_allocated_account();
+    make_temperature_check(
+        &mut ledger,
+        governance_component,
+        author_account,
+        &author_pk,
+        create_temp_check_draft(),
+    );
+
+    // Pause creation only for a day
+    let flags = PauseFlags {
+        creation: true,
+        temperature_check_voting: false,
+        proposal_voting: false,
+    };
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(owner_account, owner_badge, dec!(1))
+        .call_method(governance_component, "pause", manifest_args!(flags))
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&owner_pk)],
+        )
+        .expect_commit_success();
+
+    let now = ledger.get_current_time(TimePrecision::Second);
+    ledger.set_current_time(now.add_days(1).unwrap().seconds_since_unix_epoch * 1000);
+
+    // Voting was never paused, so there is nothing to extend
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(owner_account, owner_badge, dec!(1))
+        .call_method(
+            governance_component,
+            "unpause",
+            manifest_args!(vec![0u64], Vec::<u64>::new()),
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&owner_pk)],
+        )
+        .expect_specific_failure(|e| {
+            format!("{:?}", e)
+                .contains("Temperature check voting was not paused, no deadlines to extend")
+        });
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(owner_account, owner_badge, dec!(1))
+        .call_method(
+            governance_component,
+            "unpause",
+            manifest_args!(Vec::<u64>::new(), Vec::<u64>::new()),
+        )
+        .build();
+    let receipt = ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&owner_pk)],
+    );
+
+    let event = receipt
+        .expect_commit_success()
+        .application_events
+        .iter()
+        .find(|(id, _)| ledger.event_name(id) == "UnpausedEvent")
+        .map(|(_, data)| scrypto_decode::<UnpausedEvent>(data).unwrap())
+        .expect("UnpausedEvent not emitted");
+    assert_eq!(event.temperature_check_voting_paused_at, None);
+    assert_eq!(event.proposal_voting_paused_at, None);
+    assert!(event.extended_temperature_check_ids.is_empty());
+}
+
+#[test]
+fn test_unpause_rejects_duplicate_ids_and_skips_cancelled_items() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (owner_badge, owner_account, owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let governance_component =
+        instantiate_governance(&mut ledger, owner_badge, create_governance_parameters());
+
+    let (author_pk, _author_sk, author_account) = ledger.new_allocated_account();
+    for _ in 0..2 {
+        make_temperature_check(
+            &mut ledger,
+            governance_component,
+            author_account,
+            &author_pk,
+            create_temp_check_draft(),
+        );
+    }
+
+    // Cancel the second temperature check and pause voting
+    let flags = PauseFlags {
+        creation: false,
+        temperature_check_voting: true,
+        proposal_voting: false,
+    };
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(owner_account, owner_badge, dec!(1))
+        .call_method(
+            governance_component,
+            "cancel_temperature_check",
+            manifest_args!(1u64, "Spam".to_string()),
+        )
+        .call_method(governance_component, "pause", manifest_args!(flags))
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&owner_pk)],
+        )
+        .expect_commit_success();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(owner_account, owner_badge, dec!(1))
+        .call_method(
+            governance_component,
+            "unpause",
+            manifest_args!(vec![0u64, 0u64], Vec::<u64>::new()),
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&owner_pk)],
+        )
+        .expect_specific_failure(|e| format!("{:?}", e).contains("Duplicate item ID: 0"));
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(owner_account, owner_badge, dec!(1))
+        .call_method(
+            governance_component,
+            "unpause",
+            manifest_args!(vec![0u64, 1u64], Vec::<u64>::new()),
+        )
+        .build();
+    let receipt = ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&owner_pk)],
+    );
+
+    let event = receipt
+        .expect_commit_success()
+        .application_events
+        .iter()
+        .find(|(id, _)| ledger.event_name(id) == "UnpausedEvent")
+        .map(|(_, data)| scrypto_decode::<UnpausedEvent>(data).unwrap())
+        .expect("UnpausedEvent not emitted");
+    assert_eq!(event.extended_temperature_check_ids, vec![0u64]);
+}
+
+/// Sets the pause flags as the owner
+fn pause_as_owner(
+    ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>,
+    governance_component: ComponentAddress,
+    owner_account: ComponentAddress,
+    owner_badge: ResourceAddress,
+    owner_pk: &Secp256k1PublicKey,
+    flags: PauseFlags,
+) {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(owner_account, owner_badge, dec!(1))
+        .call_method(governance_component, "pause", manifest_args!(flags))
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(owner_pk)],
+        )
+        .expect_commit_success();
+}
+
+/// Lifts the pause as the owner, extending the given items
+/// Returns the receipt
+fn unpause_as_owner(
+    ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>,
+    governance_component: ComponentAddress,
+    owner_account: ComponentAddress,
+    owner_badge: ResourceAddress,
+    owner_pk: &Secp256k1PublicKey,
+    extend_temperature_check_ids: Vec<u64>,
+    extend_proposal_ids: Vec<u64>,
+) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(owner_account, owner_badge, dec!(1))
+        .call_method(
+            governance_component,
+            "unpause",
+            manifest_args!(extend_temperature_check_ids, extend_proposal_ids),
+        )
+        .build();
+    ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(owner_pk)],
+    )
+}
+
+/// Moves the ledger clock forward by whole days
+fn advance_days(ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>, days: i64) {
+    let now = ledger.get_current_time(TimePrecision::Second);
+    ledger.set_current_time(now.add_days(days).unwrap().seconds_since_unix_epoch * 1000);
+}
+
+/// Creates temperature check 0, elevates it to proposal 0 and creates open temperature check 1
+fn setup_open_proposal_and_temperature_check(
+    ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>,
+    governance_component: ComponentAddress,
+    owner_account: ComponentAddress,
+    owner_badge: ResourceAddress,
+    owner_pk: &Secp256k1PublicKey,
+) {
+    let (author_pk, _author_sk, author_account) = ledger.new_allocated_account();
+    make_temperature_check(
+        ledger,
+        governance_component,
+        author_account,
+        &author_pk,
+        create_temp_check_draft(),
+    );
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(owner_account, owner_badge, dec!(1))
+        .call_method(
+            governance_component,
+            "make_proposal",
+            manifest_args!(0u64, None::<EligibilityRule>),
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(owner_pk)],
+        )
+        .expect_commit_success();
+    make_temperature_check(
+        ledger,
+        governance_component,
+        author_account,
+        &author_pk,
+        create_temp_check_draft(),
+    );
+}
+
+#[test]
+fn test_proposal_only_pause_extends_only_proposals() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (owner_badge, owner_account, owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let governance_component =
+        instantiate_governance(&mut ledger, owner_badge, create_governance_parameters());
+    setup_open_proposal_and_temperature_check(
+        &mut ledger,
+        governance_component,
+        owner_account,
+        owner_badge,
+        &owner_pk,
+    );
+
+    let flags = PauseFlags {
+        creation: false,
+        temperature_check_voting: false,
+        proposal_voting: true,
+    };
+    pause_as_owner(
+        &mut ledger,
+        governance_component,
+        owner_account,
+        owner_badge,
+        &owner_pk,
+        flags,
+    );
+    advance_days(&mut ledger, 1);
+
+    // Temperature check voting was never paused, so its items cannot be extended
+    unpause_as_owner(
+        &mut ledger,
+        governance_component,
+        owner_account,
+        owner_badge,
+        &owner_pk,
+        vec![1],
+        vec![0],
+    )
+    .expect_specific_failure(|e| {
+        format!("{:?}", e)
+            .contains("Temperature check voting was not paused, no deadlines to extend")
+    });
+
+    let receipt = unpause_as_owner(
+        &mut ledger,
+        governance_component,
+        owner_account,
+        owner_badge,
+        &owner_pk,
+        vec![],
+        vec![0],
+    );
+    let commit = receipt.expect_commit_success();
+    let event = commit
+        .application_events
+        .iter()
+        .find(|(id, _)| ledger.event_name(id) == "UnpausedEvent")
+        .map(|(_, data)| scrypto_decode::<UnpausedEvent>(data).unwrap())
+        .expect("UnpausedEvent not emitted");
+    assert_eq!(event.temperature_check_voting_paused_at, None);
+    assert_eq!(event.proposal_voting_paused_at, Some(event.paused_at));
+    assert_eq!(event.extended_proposal_ids, vec![0u64]);
+
+    let extension = commit
+        .application_events
+        .iter()
+        .find(|(id, _)| ledger.event_name(id) == "DeadlineExtendedEvent")
+        .map(|(_, data)| scrypto_decode::<DeadlineExtendedEvent>(data).unwrap())
+        .expect("DeadlineExtendedEvent not emitted");
+    assert_eq!(extension.item_kind, ItemKind::Proposal);
+    assert_eq!(
+        extension.new_deadline.seconds_since_unix_epoch
+            - extension.previous_deadline.seconds_since_unix_epoch,
+        24 * 60 * 60
+    );
+}
+
+#[test]
+fn test_pause_flags_changed_mid_pause() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (owner_badge, owner_account, owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let governance_component =
+        instantiate_governance(&mut ledger, owner_badge, create_governance_parameters());
+    setup_open_proposal_and_temperature_check(
+        &mut ledger,
+        governance_component,
+        owner_account,
+        owner_badge,
+        &owner_pk,
+    );
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+
+    // Day 0: pause temperature check voting
+    let mut flags = PauseFlags {
+        creation: false,
+        temperature_check_voting: true,
+        proposal_voting: false,
+    };
+    pause_as_owner(
+        &mut ledger,
+        governance_component,
+        owner_account,
+        owner_badge,
+        &owner_pk,
+        flags,
+    );
+
+    // Day 1: also pause proposal voting, which starts its own pause
+    advance_days(&mut ledger, 1);
+    let proposal_voting_paused_at = ledger.get_current_time(TimePrecision::Second);
+    flags.proposal_voting = true;
+    pause_as_owner(
+        &mut ledger,
+        governance_component,
+        owner_account,
+        owner_badge,
+        &owner_pk,
+        flags,
+    );
+
+    // Day 2: lift temperature check voting, which ends its pause
+    advance_days(&mut ledger, 1);
+    flags.temperature_check_voting = false;
+    pause_as_owner(
+        &mut ledger,
+        governance_component,
+        owner_account,
+        owner_badge,
+        &owner_pk,
+        flags,
+    );
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "vote_on_temperature_check",
+            manifest_args!(
+                voter_account,
+                1u64,
+                TemperatureCheckVote::For,
+                None::<VoteRationale>,
+                None::<ManifestProof>
+            ),
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&voter_pk)],
+        )
+        .expect_commit_success();
+
+    // Day 3: the lifted kind has nothing to extend, proposals move out by two days
+    advance_days(&mut ledger, 1);
+    unpause_as_owner(
+        &mut ledger,
+        governance_component,
+        owner_account,
+        owner_badge,
+        &owner_pk,
+        vec![1],
+        vec![],
+    )
+    .expect_specific_failure(|e| {
+        format!("{:?}", e)
+            .contains("Temperature check voting was not paused, no deadlines to extend")
+    });
+
+    let receipt = unpause_as_owner(
+        &mut ledger,
+        governance_component,
+        owner_account,
+        owner_badge,
+        &owner_pk,
+        vec![],
+        vec![0],
+    );
+    let commit = receipt.expect_commit_success();
+    let event = commit
+        .application_events
+        .iter()
+        .find(|(id, _)| ledger.event_name(id) == "UnpausedEvent")
+        .map(|(_, data)| scrypto_decode::<UnpausedEvent>(data).unwrap())
+        .expect("UnpausedEvent not emitted");
+    assert_eq!(event.temperature_check_voting_paused_at, None);
+    assert_eq!(
+        event.proposal_voting_paused_at,
+        Some(proposal_voting_paused_at)
+    );
+    assert_eq!(event.extended_proposal_ids, vec![0u64]);
+
+    let extension = commit
+        .application_events
+        .iter()
+        .find(|(id, _)| ledger.event_name(id) == "DeadlineExtendedEvent")
+        .map(|(_, data)| scrypto_decode::<DeadlineExtendedEvent>(data).unwrap())
+        .expect("DeadlineExtendedEvent not emitted");
+    assert_eq!(
+        extension.new_deadline.seconds_since_unix_epoch
+            - extension.previous_deadline.seconds_since_unix_epoch,
+        2 * 24 * 60 * 60
+    );
+}
+
+#[test]
+fn test_pause_requires_owner() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (owner_badge, _owner_account, _owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let governance_component =
+        instantiate_governance(&mut ledger, owner_badge, create_governance_parameters());
+
+    let flags = PauseFlags {
+        creation: true,
+        temperature_check_voting: true,
+        proposal_voting: true,
+    };
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "pause", manifest_args!(flags))
+        .build();
+    ledger
+        .execute_manifest(manifest, vec![])
+        .expect_commit_failure();
+}
+
+// =============================================================================
+// Cancellation and Withdrawal Tests
+// =============================================================================
+
+#[test]
+fn test_author_can_withdraw_before_votes() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (owner_badge, owner_account, owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let governance_component =
+        instantiate_governance(&mut ledger, owner_badge, create_governance_parameters());
+
+    let (author_pk, _author_sk, author_account) = ledger.new_allocated_account();
+    let (other_pk, _other_sk, other_account) = ledger.new_allocated_account();
+
+    make_temperature_check(
+        &mut ledger,
+        governance_component,
+        author_account,
+        &author_pk,
+        create_temp_check_draft(),
+    );
+
+    // Another account cannot withdraw it
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "withdraw_temperature_check",
+            manifest_args!(other_account, 0u64),
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&other_pk)],
+        )
+        .expect_commit_failure();
+
+    // The author can
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "withdraw_temperature_check",
+            manifest_args!(author_account, 0u64),
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&author_pk)],
+        )
+        .expect_commit_success();
+
+    // A withdrawn temperature check rejects votes and elevation
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "vote_on_temperature_check",
+            manifest_args!(
+                other_account,
+                0u64,
+                TemperatureCheckVote::For,
+                None::<VoteRationale>,
+                None::<ManifestProof>
+            ),
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&other_pk)],
+        )
+        .expect_commit_failure();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(owner_account, owner_badge, dec!(1))
+        .call_method(governance_component, "make_proposal", manifest_args!(0u64, None::<EligibilityRule>))
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&owner_pk)],
+        )
+        .expect_commit_failure();
+}
+
+#[test]
+fn test_author_cannot_withdraw_after_votes() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (owner_badge, _owner_account, _owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let governance_component =
+        instantiate_governance(&mut ledger, owner_badge, create_governance_parameters());
+
+    let (author_pk, _author_sk, author_account) = ledger.new_allocated_account();
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+
+    make_temperature_check(
+        &mut ledger,
+        governance_component,
+        author_account,
+        &author_pk,
+        create_temp_check_draft(),
+    );
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "vote_on_temperature_check",
+            manifest_args!(
+                voter_account,
+                0u64,
+                TemperatureCheckVote::For,
+                None::<VoteRationale>,
+                None::<ManifestProof>
+            ),
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&voter_pk)],
+        )
+        .expect_commit_success();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "withdraw_temperature_check",
+            manifest_args!(author_account, 0u64),
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&author_pk)],
+        )
+        .expect_commit_failure();
+}
+
+#[test]
+fn test_owner_can_cancel_proposal() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (owner_badge, owner_account, owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let governance_component =
+        instantiate_governance(&mut ledger, owner_badge, create_governance_parameters());
+
+    let (author_pk, _author_sk, author_account) = ledger.new_allocated_account();
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+
+    make_temperature_check(
+        &mut ledger,
+        governance_component,
+        author_account,
+        &author_pk,
+        create_temp_check_draft(),
+    );
+
+    // Elevate and cancel the proposal in one transaction
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(owner_account, owner_badge, dec!(1))
+        .call_method(governance_component, "make_proposal", manifest_args!(0u64, None::<EligibilityRule>))
+        .call_method(
+            governance_component,
+            "cancel_proposal",
+            manifest_args!(0u64, "Duplicate of an earlier proposal".to_string()),
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&owner_pk)],
+        )
+        .expect_commit_success();
+
+    let votes: Vec<ProposalVoteOptionId> = vec![ProposalVoteOptionId(0)];
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "vote_on_proposal",
+            manifest_args!(
+                voter_account,
+                0u64,
+                votes,
+                None::<VoteRationale>,
+                None::<ManifestProof>
+            ),
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&voter_pk)],
+        )
+        .expect_commit_failure();
+}
+
+#[test]
+fn test_owner_cannot_cancel_closed_proposal() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (owner_badge, owner_account, owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let governance_component =
+        instantiate_governance(&mut ledger, owner_badge, create_governance_parameters());
+
+    let (author_pk, _author_sk, author_account) = ledger.new_allocated_account();
+
+    make_temperature_check(
+        &mut ledger,
+        governance_component,
+        author_account,
+        &author_pk,
+        create_temp_check_draft(),
+    );
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(owner_account, owner_badge, dec!(1))
+        .call_method(governance_component, "make_proposal", manifest_args!(0u64, None::<EligibilityRule>))
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&owner_pk)],
+        )
+        .expect_commit_success();
+
+    // Move past the proposal deadline
+    let now = ledger.get_current_time(TimePrecision::Second);
+    ledger.set_current_time(now.add_days(15).unwrap().seconds_since_unix_epoch * 1000);
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(owner_account, owner_badge, dec!(1))
+        .call_method(
+            governance_component,
+            "cancel_proposal",
+            manifest_args!(0u64, "Too late".to_string()),
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&owner_pk)],
+        )
+        .expect_specific_failure(|e| format!("{:?}", e).contains("Voting has ended"));
+}
+
+// =============================================================================
+// Voting Window Tests
+// =============================================================================
+
+#[test]
+fn test_scheduled_start_delays_voting() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (owner_badge, _owner_account, _owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let governance_component =
+        instantiate_governance(&mut ledger, owner_badge, create_governance_parameters());
+
+    let (author_pk, _author_sk, author_account) = ledger.new_allocated_account();
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+
+    // Schedule voting to open in one day
+    let now = ledger.get_current_time(TimePrecision::Second);
+    let mut draft = create_temp_check_draft();
+    draft.start = Some(now.add_days(1).unwrap());
+    make_temperature_check(
+        &mut ledger,
+        governance_component,
+        author_account,
+        &author_pk,
+        draft,
+    );
+
+    // Voting fails during the review period
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "vote_on_temperature_check",
+            manifest_args!(
+                voter_account,
+                0u64,
+                TemperatureCheckVote::For,
+                None::<VoteRationale>,
+                None::<ManifestProof>
+            ),
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&voter_pk)],
+        )
+        .expect_commit_failure();
+
+    // Voting succeeds once the start has passed
+    ledger.set_current_time(now.add_days(2).unwrap().seconds_since_unix_epoch * 1000);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "vote_on_temperature_check",
+            manifest_args!(
+                voter_account,
+                0u64,
+                TemperatureCheckVote::For,
+                None::<VoteRationale>,
+                None::<ManifestProof>
+            ),
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&voter_pk)],
+        )
+        .expect_commit_success();
+}
+
+#[test]
+fn test_start_beyond_max_delay_fails() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (owner_badge, _owner_account, _owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let governance_component =
+        instantiate_governance(&mut ledger, owner_badge, create_governance_parameters());
+
+    let (author_pk, _author_sk, author_account) = ledger.new_allocated_account();
+
+    let now = ledger.get_current_time(TimePrecision::Second);
+    let mut draft = create_temp_check_draft();
+    draft.start = Some(now.add_days(30).unwrap());
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "make_temperature_check",
+            manifest_args!(author_account, draft),
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&author_pk)],
+        )
+        .expect_commit_failure();
+}
+
+#[test]
+fn test_owner_deadline_extension_is_bounded() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (owner_badge, owner_account, owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let governance_component =
+        instantiate_governance(&mut ledger, owner_badge, create_governance_parameters());
+
+    let (author_pk, _author_sk, author_account) = ledger.new_allocated_account();
+    make_temperature_check(
+        &mut ledger,
+        governance_component,
+        author_account,
+        &author_pk,
+        create_temp_check_draft(),
+    );
+
+    // Two days fit within the three day maximum
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(owner_account, owner_badge, dec!(1))
+        .call_method(
+            governance_component,
+            "extend_temperature_check_deadline",
+            manifest_args!(0u64, 2u32 * 24 * 60),
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&owner_pk)],
+        )
+        .expect_commit_success();
+
+    // Another two days would exceed it, as would an extension that overflows the total
+    for minutes in [2u32 * 24 * 60, u32::MAX] {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_amount(owner_account, owner_badge, dec!(1))
+            .call_method(
+                governance_component,
+                "extend_temperature_check_deadline",
+                manifest_args!(0u64, minutes),
+            )
+            .build();
+        ledger
+            .execute_manifest(
+                manifest,
+                vec![NonFungibleGlobalId::from_public_key(&owner_pk)],
+            )
+            .expect_specific_failure(|e| {
+                format!("{:?}", e).contains("Deadline cannot be extended by more than")
+            });
+    }
+}
+
+#[test]
+fn test_late_vote_extends_deadline() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (owner_badge, _owner_account, _owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let mut params = create_governance_parameters();
+    params.anti_sniping = Some(AntiSnipingRule {
+        window_minutes: 60,
+        extension_minutes: 30,
+        max_extension_minutes: 60,
+    });
+    let governance_component = instantiate_governance(&mut ledger, owner_badge, params);
+
+    let (author_pk, _author_sk, author_account) = ledger.new_allocated_account();
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+    let (late_voter_pk, _late_voter_sk, late_voter_account) = ledger.new_allocated_account();
+
+    let now = ledger.get_current_time(TimePrecision::Second);
+    make_temperature_check(
+        &mut ledger,
+        governance_component,
+        author_account,
+        &author_pk,
+        create_temp_check_draft(),
+    );
+    let deadline = now.add_days(7).unwrap();
+
+    // Vote 10 minutes before the deadline, moving it out by 30 minutes
+    ledger.set_current_time(deadline.add_minutes(-10).unwrap().seconds_since_unix_epoch * 1000);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "vote_on_temperature_check",
+            manifest_args!(
+                voter_account,
+                0u64,
+                TemperatureCheckVote::For,
+                None::<VoteRationale>,
+                None::<ManifestProof>
+            ),
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&voter_pk)],
+        )
+        .expect_commit_success();
+
+    // A vote 10 minutes after the original deadline is still accepted
+    ledger.set_current_time(deadline.add_minutes(10).unwrap().seconds_since_unix_epoch * 1000);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "vote_on_temperature_check",
+            manifest_args!(
+                late_voter_account,
+                0u64,
+                TemperatureCheckVote::Against,
+                None::<VoteRationale>,
+                None::<ManifestProof>
+            ),
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&late_voter_pk)],
+        )
+        .expect_commit_success();
+
+    // The cap of 60 minutes holds: the deadline is at most original + 60 minutes
+    let (capped_voter_pk, _capped_voter_sk, capped_voter_account) =
+        ledger.new_allocated_account();
+    ledger.set_current_time(deadline.add_minutes(61).unwrap().seconds_since_unix_epoch * 1000);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "vote_on_temperature_check",
+            manifest_args!(
+                capped_voter_account,
+                0u64,
+                TemperatureCheckVote::For,
+                None::<VoteRationale>,
+                None::<ManifestProof>
+            ),
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&capped_voter_pk)],
+        )
+        .expect_commit_failure();
+}
+
+// =============================================================================
+// Revote Policy Tests
+// =============================================================================
+
+/// Casts a vote on temperature check 0 and returns whether it committed successfully
+fn vote_on_first_temperature_check(
+    ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>,
+    governance_component: ComponentAddress,
+    voter_account: ComponentAddress,
+    voter_pk: &Secp256k1PublicKey,
+    vote: TemperatureCheckVote,
+) -> bool {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "vote_on_temperature_check",
+            manifest_args!(voter_account, 0u64, vote, None::<VoteRationale>, None::<ManifestProof>),
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(voter_pk)],
+        )
+        .is_commit_success()
+}
+
+#[test]
+fn test_revote_limit_is_enforced() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (owner_badge, _owner_account, _owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let mut params = create_governance_parameters();
+    params.revote_policy = RevotePolicy::AtMost(1);
+    let governance_component = instantiate_governance(&mut ledger, owner_badge, params);
+
+    let (author_pk, _author_sk, author_account) = ledger.new_allocated_account();
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+    make_temperature_check(
+        &mut ledger,
+        governance_component,
+        author_account,
+        &author_pk,
+        create_temp_check_draft(),
+    );
+
+    // Initial vote and one revote succeed, a second revote fails
+    for (vote, expected) in [
+        (TemperatureCheckVote::For, true),
+        (TemperatureCheckVote::Against, true),
+        (TemperatureCheckVote::For, false),
+    ] {
+        let success = vote_on_first_temperature_check(
+            &mut ledger,
+            governance_component,
+            voter_account,
+            &voter_pk,
+            vote,
+        );
+        assert_eq!(success, expected);
+    }
+
+    // The policy is exposed through a getter
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "get_temperature_check_revote_policy",
+            manifest_args!(0u64),
+        )
+        .build();
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let policy: RevotePolicy = receipt.expect_commit_success().output(1);
+    assert_eq!(policy, RevotePolicy::AtMost(1));
+}
+
+#[test]
+fn test_revote_locked_near_deadline() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (owner_badge, _owner_account, _owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let mut params = create_governance_parameters();
+    params.revote_policy = RevotePolicy::UntilLockWindow { lock_minutes: 60 };
+    let governance_component = instantiate_governance(&mut ledger, owner_badge, params);
+
+    let (author_pk, _author_sk, author_account) = ledger.new_allocated_account();
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+    let now = ledger.get_current_time(TimePrecision::Second);
+    make_temperature_check(
+        &mut ledger,
+        governance_component,
+        author_account,
+        &author_pk,
+        create_temp_check_draft(),
+    );
+    let deadline = now.add_days(7).unwrap();
+
+    assert!(vote_on_first_temperature_check(
+        &mut ledger,
+        governance_component,
+        voter_account,
+        &voter_pk,
+        TemperatureCheckVote::For,
+    ));
+    assert!(vote_on_first_temperature_check(
+        &mut ledger,
+        governance_component,
+        voter_account,
+        &voter_pk,
+        TemperatureCheckVote::Against,
+    ));
+
+    // Within the lock window the vote is final
+    ledger.set_current_time(deadline.add_minutes(-30).unwrap().seconds_since_unix_epoch * 1000);
+    assert!(!vote_on_first_temperature_check(
+        &mut ledger,
+        governance_component,
+        voter_account,
+        &voter_pk,
+        TemperatureCheckVote::For,
+    ));
+}
+
+// =============================================================================
+// Vote Retraction Tests
+// =============================================================================
+
+/// Retracts the account's vote on temperature check 0 and returns whether it committed successfully
+fn retract_first_temperature_check_vote(
+    ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>,
+    governance_component: ComponentAddress,
+    voter_account: ComponentAddress,
+    voter_pk: &Secp256k1PublicKey,
+) -> bool {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "retract_temperature_check_vote",
+            manifest_args!(voter_account, 0u64),
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(voter_pk)],
+        )
+        .is_commit_success()
+}
+
+#[test]
+fn test_retract_and_vote_again() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (owner_badge, _owner_account, _owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let mut params = create_governance_parameters();
+    params.revote_policy = RevotePolicy::Unlimited;
+    let governance_component = instantiate_governance(&mut ledger, owner_badge, params);
+
+    let (author_pk, _author_sk, author_account) = ledger.new_allocated_account();
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+    make_temperature_check(
+        &mut ledger,
+        governance_component,
+        author_account,
+        &author_pk,
+        create_temp_check_draft(),
+    );
+
+    // Cannot retract without having voted
+    assert!(!retract_first_temperature_check_vote(
+        &mut ledger,
+        governance_component,
+        voter_account,
+        &voter_pk,
+    ));
+
+    assert!(vote_on_first_temperature_check(
+        &mut ledger,
+        governance_component,
+        voter_account,
+        &voter_pk,
+        TemperatureCheckVote::For,
+    ));
+    assert!(retract_first_temperature_check_vote(
+        &mut ledger,
+        governance_component,
+        voter_account,
+        &voter_pk,
+    ));
+
+    // Cannot retract twice
+    assert!(!retract_first_temperature_check_vote(
+        &mut ledger,
+        governance_component,
+        voter_account,
+        &voter_pk,
+    ));
+
+    // Can vote again after retracting
+    assert!(vote_on_first_temperature_check(
+        &mut ledger,
+        governance_component,
+        voter_account,
+        &voter_pk,
+        TemperatureCheckVote::Against,
+    ));
+}
+
+#[test]
+fn test_retraction_respects_revote_policy() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (owner_badge, _owner_account, _owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let mut params = create_governance_parameters();
+    params.revote_policy = RevotePolicy::AtMost(1);
+    let governance_component = instantiate_governance(&mut ledger, owner_badge, params);
+
+    let (author_pk, _author_sk, author_account) = ledger.new_allocated_account();
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+    make_temperature_check(
+        &mut ledger,
+        governance_component,
+        author_account,
+        &author_pk,
+        create_temp_check_draft(),
+    );
+
+    assert!(vote_on_first_temperature_check(
+        &mut ledger,
+        governance_component,
+        voter_account,
+        &voter_pk,
+        TemperatureCheckVote::For,
+    ));
+
+    // The retraction uses up the single allowed change
+    assert!(retract_first_temperature_check_vote(
+        &mut ledger,
+        governance_component,
+        voter_account,
+        &voter_pk,
+    ));
+    assert!(!vote_on_first_temperature_check(
+        &mut ledger,
+        governance_component,
+        voter_account,
+        &voter_pk,
+        TemperatureCheckVote::Against,
+    ));
+}
+
+#[test]
+fn test_retraction_does_not_extend_deadline() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (owner_badge, _owner_account, _owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let mut params = create_governance_parameters();
+    params.revote_policy = RevotePolicy::Unlimited;
+    params.anti_sniping = Some(AntiSnipingRule {
+        window_minutes: 60,
+        extension_minutes: 30,
+        max_extension_minutes: 60,
+    });
+    let governance_component = instantiate_governance(&mut ledger, owner_badge, params);
+
+    let (author_pk, _author_sk, author_account) = ledger.new_allocated_account();
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+    let (late_voter_pk, _late_voter_sk, late_voter_account) = ledger.new_allocated_account();
+
+    let now = ledger.get_current_time(TimePrecision::Second);
+    make_temperature_check(
+        &mut ledger,
+        governance_component,
+        author_account,
+        &author_pk,
+        create_temp_check_draft(),
+    );
+    let deadline = now.add_days(7).unwrap();
+
+    // Vote well before the anti-sniping window
+    assert!(vote_on_first_temperature_check(
+        &mut ledger,
+        governance_component,
+        voter_account,
+        &voter_pk,
+        TemperatureCheckVote::For,
+    ));
+
+    // Retract 10 minutes before the deadline, inside the window
+    ledger.set_current_time(deadline.add_minutes(-10).unwrap().seconds_since_unix_epoch * 1000);
+    assert!(retract_first_temperature_check_vote(
+        &mut ledger,
+        governance_component,
+        voter_account,
+        &voter_pk,
+    ));
+
+    // The deadline did not move, so a vote after it is rejected
+    ledger.set_current_time(deadline.add_minutes(10).unwrap().seconds_since_unix_epoch * 1000);
+    assert!(!vote_on_first_temperature_check(
+        &mut ledger,
+        governance_component,
+        late_voter_account,
+        &late_voter_pk,
+        TemperatureCheckVote::Against,
+    ));
+}
+
+// =============================================================================
+// Vote Rationale Tests
+// =============================================================================
+
+#[test]
+fn test_vote_with_rationale() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (owner_badge, _owner_account, _owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let governance_component =
+        instantiate_governance(&mut ledger, owner_badge, create_governance_parameters());
+
+    let (author_pk, _author_sk, author_account) = ledger.new_allocated_account();
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+    let (other_voter_pk, _other_voter_sk, other_voter_account) = ledger.new_allocated_account();
+    make_temperature_check(
+        &mut ledger,
+        governance_component,
+        author_account,
+        &author_pk,
+        create_temp_check_draft(),
+    );
+
+    // A rationale over the length limit is rejected
+    let rationale = Some(VoteRationale::Text("x".repeat(281)));
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "vote_on_temperature_check",
+            manifest_args!(
+                voter_account,
+                0u64,
+                TemperatureCheckVote::For,
+                rationale,
+                None::<ManifestProof>
+            ),
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&voter_pk)],
+        )
+        .expect_commit_failure();
+
+    // Text and URL rationales within the limit are accepted
+    let rationale = Some(VoteRationale::Text(
+        "Lower fees help adoption more than they hurt validators".to_string(),
+    ));
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "vote_on_temperature_check",
+            manifest_args!(
+                voter_account,
+                0u64,
+                TemperatureCheckVote::For,
+                rationale,
+                None::<ManifestProof>
+            ),
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&voter_pk)],
+        )
+        .expect_commit_success();
+
+    let rationale = Some(VoteRationale::Url(Url::of(
+        "https://radixtalk.com/t/why-i-vote-against/42",
+    )));
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "vote_on_temperature_check",
+            manifest_args!(
+                other_voter_account,
+                0u64,
+                TemperatureCheckVote::Against,
+                rationale,
+                None::<ManifestProof>
+            ),
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&other_voter_pk)],
+        )
+        .expect_commit_success();
+}
+
+// =============================================================================
+// Batch Voting Tests
+// =============================================================================
+
+/// Manifest-side encoding of a `BallotInput` (accounts are passed as addresses)
+type ManifestBallot = (ComponentAddress, u64, BallotVote, Option<VoteRationale>);
+
+#[test]
+fn test_vote_batch_across_accounts_and_items() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (owner_badge, owner_account, owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let governance_component =
+        instantiate_governance(&mut ledger, owner_badge, create_governance_parameters());
+
+    let (author_pk, _author_sk, author_account) = ledger.new_allocated_account();
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+    let (second_pk, _second_sk, second_account) = ledger.new_allocated_account();
+
+    // Temperature check 0 gets elevated to proposal 0, temperature check 1 stays open
+    make_temperature_check(
+        &mut ledger,
+        governance_component,
+        author_account,
+        &author_pk,
+        create_temp_check_draft(),
+    );
+    make_temperature_check(
+        &mut ledger,
+        governance_component,
+        author_account,
+        &author_pk,
+        create_temp_check_draft(),
+    );
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(owner_account, owner_badge, dec!(1))
+        .call_method(governance_component, "make_proposal", manifest_args!(0u64, None::<EligibilityRule>))
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&owner_pk)],
+        )
+        .expect_commit_success();
+
+    let ballots: Vec<ManifestBallot> = vec![
+        (
+            voter_account,
+            1,
+            BallotVote::TemperatureCheck(TemperatureCheckVote::For),
+            None,
+        ),
+        (
+            voter_account,
+            0,
+            BallotVote::Proposal(vec![ProposalVoteOptionId(1)]),
+            None,
+        ),
+        (
+            second_account,
+            1,
+            BallotVote::TemperatureCheck(TemperatureCheckVote::Against),
+            Some(VoteRationale::Text("Not convinced yet".to_string())),
+        ),
+    ];
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "vote_batch", manifest_args!(ballots))
+        .build();
+    let receipt = ledger.execute_manifest(
+        manifest,
+        vec![
+            NonFungibleGlobalId::from_public_key(&voter_pk),
+            NonFungibleGlobalId::from_public_key(&second_pk),
+        ],
+    );
+    receipt.expect_commit_success();
+
+    // Each ballot emits its own voted event
+    let voted_events = receipt
+        .expect_commit_success()
+        .application_events
+        .iter()
+        .filter(|(id, _)| {
+            let name = ledger.event_name(id);
+            name == "TemperatureCheckVotedEvent" || name == "ProposalVotedEvent"
+        })
+        .count();
+    assert_eq!(voted_events, 3);
+}
+
+#[test]
+fn test_vote_batch_is_atomic() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (owner_badge, _owner_account, _owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let governance_component =
+        instantiate_governance(&mut ledger, owner_badge, create_governance_parameters());
+
+    let (author_pk, _author_sk, author_account) = ledger.new_allocated_account();
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+    make_temperature_check(
+        &mut ledger,
+        governance_component,
+        author_account,
+        &author_pk,
+        create_temp_check_draft(),
+    );
+
+    // The second ballot targets a temperature check that does not exist
+    let ballots: Vec<ManifestBallot> = vec![
+        (
+            voter_account,
+            0,
+            BallotVote::TemperatureCheck(TemperatureCheckVote::For),
+            None,
+        ),
+        (
+            voter_account,
+            7,
+            BallotVote::TemperatureCheck(TemperatureCheckVote::For),
+            None,
+        ),
+    ];
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "vote_batch", manifest_args!(ballots))
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&voter_pk)],
+        )
+        .expect_commit_failure();
+
+    // Nothing was recorded, so a first vote (votes are final) still succeeds
+    assert!(vote_on_first_temperature_check(
+        &mut ledger,
+        governance_component,
+        voter_account,
+        &voter_pk,
+        TemperatureCheckVote::Against,
+    ));
+}
+
+// =============================================================================
+// Voting Fee Tests
+// =============================================================================
+
+/// Returns a draft whose markdown description is roughly 40 KB
+fn create_large_temp_check_draft() -> TemperatureCheckDraft {
+    let mut draft = create_temp_check_draft();
+    draft.description = Description::Inline(format!(
+        "# Large Proposal\n\n{}",
+        "Lorem ipsum dolor sit amet, consectetur. ".repeat(1000)
+    ));
+    draft
+}
+
+/// Executes a vote manifest and returns the execution cost units it consumed
+fn vote_execution_cost(
+    ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>,
+    manifest: TransactionManifestV1,
+    voter_pk: &Secp256k1PublicKey,
+) -> u32 {
+    let receipt = ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(voter_pk)],
+    );
+    receipt.expect_commit_success();
+    receipt.fee_summary.total_execution_cost_units_consumed
+}
+
+#[test]
+fn test_vote_cost_does_not_grow_with_description_size() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (owner_badge, owner_account, owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let governance_component =
+        instantiate_governance(&mut ledger, owner_badge, create_governance_parameters());
+
+    let (author_pk, _author_sk, author_account) = ledger.new_allocated_account();
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+
+    // Temperature check 0 has a short description, temperature check 1 a large one
+    make_temperature_check(
+        &mut ledger,
+        governance_component,
+        author_account,
+        &author_pk,
+        create_temp_check_draft(),
+    );
+    make_temperature_check(
+        &mut ledger,
+        governance_component,
+        author_account,
+        &author_pk,
+        create_large_temp_check_draft(),
+    );
+
+    let mut temperature_check_costs = Vec::new();
+    for temperature_check_id in [0u64, 1u64] {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                governance_component,
+                "vote_on_temperature_check",
+                manifest_args!(
+                    voter_account,
+                    temperature_check_id,
+                    TemperatureCheckVote::For,
+                    None::<VoteRationale>,
+                    None::<ManifestProof>
+                ),
+            )
+            .build();
+        temperature_check_costs.push(vote_execution_cost(&mut ledger, manifest, &voter_pk));
+    }
+
+    // Elevate both to proposals 0 and 1
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(owner_account, owner_badge, dec!(1))
+        .call_method(governance_component, "make_proposal", manifest_args!(0u64, None::<EligibilityRule>))
+        .call_method(governance_component, "make_proposal", manifest_args!(1u64, None::<EligibilityRule>))
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&owner_pk)],
+        )
+        .expect_commit_success();
+
+    let mut proposal_costs = Vec::new();
+    for proposal_id in [0u64, 1u64] {
+        let votes: Vec<ProposalVoteOptionId> = vec![ProposalVoteOptionId(0)];
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                governance_component,
+                "vote_on_proposal",
+                manifest_args!(
+                    voter_account,
+                    proposal_id,
+                    votes,
+                    None::<VoteRationale>,
+                    None::<ManifestProof>
+                ),
+            )
+            .build();
+        proposal_costs.push(vote_execution_cost(&mut ledger, manifest, &voter_pk));
+    }
+
+    // Voting only loads the compact header, so the large description adds (almost) nothing
+    for costs in [temperature_check_costs, proposal_costs] {
+        let (small, large) = (costs[0], costs[1]);
+        assert!(
+            large <= small + small / 100,
+            "Vote on large item cost {} units, vote on small item cost {} units",
+            large,
+            small
+        );
+    }
+}
+
+// =============================================================================
+// Content Reference Tests
+// =============================================================================
+
+/// Returns a draft whose description is stored off-ledger at the given location
+fn create_content_ref_temp_check_draft(location: Option<ContentLocation>) -> TemperatureCheckDraft {
+    let markdown = "# Off-Ledger Proposal\n\nA description hosted on IPFS.";
+    let mut draft = create_temp_check_draft();
+    draft.description = Description::Ref(ContentRef {
+        hash: hash(markdown.as_bytes()),
+        location,
+    });
+    draft
+}
+
+#[test]
+fn test_content_ref_temperature_check_can_be_elevated_and_voted() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (owner_badge, owner_account, owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let governance_component =
+        instantiate_governance(&mut ledger, owner_badge, create_governance_parameters());
+
+    let (author_pk, _author_sk, author_account) = ledger.new_allocated_account();
+    let draft = create_content_ref_temp_check_draft(Some(ContentLocation::IpfsCid(
+        "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi".to_string(),
+    )));
+    make_temperature_check(&mut ledger, governance_component, author_account, &author_pk, draft);
+
+    // The proposal references the temperature check content
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(owner_account, owner_badge, dec!(1))
+        .call_method(governance_component, "make_proposal", manifest_args!(0u64, None::<EligibilityRule>))
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&owner_pk)],
+        )
+        .expect_commit_success();
+
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+    let votes: Vec<ProposalVoteOptionId> = vec![ProposalVoteOptionId(0)];
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "vote_on_proposal",
+            manifest_args!(
+                voter_account,
+                0u64,
+                votes,
+                None::<VoteRationale>,
+                None::<ManifestProof>
+            ),
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&voter_pk)],
+        )
+        .expect_commit_success();
+}
+
+#[test]
+fn test_inline_description_cannot_exceed_byte_cap() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (owner_badge, _owner_account, _owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let mut params = create_governance_parameters();
+    params.draft_limits.max_inline_description_bytes = 1024;
+    let governance_component = instantiate_governance(&mut ledger, owner_badge, params);
+
+    let (author_pk, _author_sk, author_account) = ledger.new_allocated_account();
+
+    // 40 KB inline description is over the 1 KB cap
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "make_temperature_check",
+            manifest_args!(author_account, create_large_temp_check_draft()),
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&author_pk)],
+        )
+        .expect_commit_failure();
+
+    // The same proposal can be submitted as an off-ledger reference
+    let draft = create_content_ref_temp_check_draft(Some(ContentLocation::Uri(Url::of(
+        "https://radixtalk.com/proposal/123.md",
+    ))));
+    make_temperature_check(&mut ledger, governance_component, author_account, &author_pk, draft);
+}
+
+#[test]
+fn test_content_ref_rejects_malformed_ipfs_cid() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (owner_badge, _owner_account, _owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let governance_component =
+        instantiate_governance(&mut ledger, owner_badge, create_governance_parameters());
+
+    let (author_pk, _author_sk, author_account) = ledger.new_allocated_account();
+    let draft = create_content_ref_temp_check_draft(Some(ContentLocation::IpfsCid(
+        "ipfs://not a cid".to_string(),
+    )));
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "make_temperature_check",
+            manifest_args!(author_account, draft),
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&author_pk)],
+        )
+        .expect_commit_failure();
+}
+
+// =============================================================================
+// Draft Validation Tests
+// =============================================================================
+
+/// Submits a temperature check draft and returns whether it was accepted
+fn try_make_temperature_check(
+    ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>,
+    governance_component: ComponentAddress,
+    author_account: ComponentAddress,
+    author_pk: &Secp256k1PublicKey,
+    draft: TemperatureCheckDraft,
+) -> bool {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "make_temperature_check",
+            manifest_args!(author_account, draft),
+        )
+        .build();
+
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(author_pk)],
+        )
+        .is_commit_success()
+}
+
+#[test]
+fn test_draft_limits_can_be_adjusted_by_owner() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (owner_badge, owner_account, owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let governance_component =
+        instantiate_governance(&mut ledger, owner_badge, create_governance_parameters());
+
+    let (author_pk, _author_sk, author_account) = ledger.new_allocated_account();
+    let mut draft = create_temp_check_draft();
+    draft.title = "T".repeat(201);
+
+    // 201 bytes is over the 200 byte title limit
+    assert!(!try_make_temperature_check(
+        &mut ledger,
+        governance_component,
+        author_account,
+        &author_pk,
+        draft.clone(),
+    ));
+
+    // The owner raises the limit without a package upgrade
+    let mut params = create_governance_parameters();
+    params.draft_limits.max_title_bytes = 300;
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(owner_account, owner_badge, dec!(1))
+        .call_method(
+            governance_component,
+            "update_governance_parameters",
+            manifest_args!(params),
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&owner_pk)],
+        )
+        .expect_commit_success();
+
+    assert!(try_make_temperature_check(
+        &mut ledger,
+        governance_component,
+        author_account,
+        &author_pk,
+        draft,
+    ));
+}
+
+#[test]
+fn test_draft_rejects_invalid_option_labels() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (owner_badge, _owner_account, _owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let governance_component =
+        instantiate_governance(&mut ledger, owner_badge, create_governance_parameters());
+
+    let (author_pk, _author_sk, author_account) = ledger.new_allocated_account();
+
+    // Duplicate label
+    let mut draft = create_temp_check_draft();
+    draft.vote_options[1].label = "For".to_string();
+    assert!(!try_make_temperature_check(
+        &mut ledger,
+        governance_component,
+        author_account,
+        &author_pk,
+        draft,
+    ));
+
+    // Blank label
+    let mut draft = create_temp_check_draft();
+    draft.vote_options[1].label = "   ".to_string();
+    assert!(!try_make_temperature_check(
+        &mut ledger,
+        governance_component,
+        author_account,
+        &author_pk,
+        draft,
+    ));
+
+    // Label over the 100 byte limit
+    let mut draft = create_temp_check_draft();
+    draft.vote_options[1].label = "A".repeat(101);
+    assert!(!try_make_temperature_check(
+        &mut ledger,
+        governance_component,
+        author_account,
+        &author_pk,
+        draft,
+    ));
+}
+
+#[test]
+fn test_draft_links_must_use_https() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (owner_badge, _owner_account, _owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let governance_component =
+        instantiate_governance(&mut ledger, owner_badge, create_governance_parameters());
+
+    let (author_pk, _author_sk, author_account) = ledger.new_allocated_account();
+
+    let mut draft = create_temp_check_draft();
+    draft.links = vec![Url::of("http://radixtalk.com/proposal/123")];
+    assert!(!try_make_temperature_check(
+        &mut ledger,
+        governance_component,
+        author_account,
+        &author_pk,
+        draft,
+    ));
+
+    let draft = create_content_ref_temp_check_draft(Some(ContentLocation::Uri(Url::of(
+        "javascript:alert(1)",
+    ))));
+    assert!(!try_make_temperature_check(
+        &mut ledger,
+        governance_component,
+        author_account,
+        &author_pk,
+        draft,
+    ));
+}
+
+// =============================================================================
+// RFC Reference and Attachment Tests
+// =============================================================================
+
+#[test]
+fn test_created_event_carries_rfc_reference_and_attachments() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (owner_badge, _owner_account, _owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let governance_component =
+        instantiate_governance(&mut ledger, owner_badge, create_governance_parameters());
+
+    let (author_pk, _author_sk, author_account) = ledger.new_allocated_account();
+
+    let rfc_reference = RfcReference {
+        url: Url::of("https://radixtalk.com/t/rfc-treasury-allocation/123"),
+        forum_thread_id: Some(123),
+    };
+    let attachment = Attachment {
+        name: "Budget breakdown".to_string(),
+        url: Url::of("https://example.com/budget.pdf"),
+        content_hash: hash("budget".as_bytes()),
+        media_type: "application/pdf".to_string(),
+    };
+    let mut draft = create_temp_check_draft();
+    draft.rfc_reference = Some(rfc_reference.clone());
+    draft.attachments = vec![attachment.clone()];
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "make_temperature_check",
+            manifest_args!(author_account, draft),
+        )
+        .build();
+    let receipt = ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&author_pk)],
+    );
+
+    let event = receipt
+        .expect_commit_success()
+        .application_events
+        .iter()
+        .find(|(id, _)| ledger.event_name(id) == "TemperatureCheckCreatedEvent")
+        .map(|(_, data)| scrypto_decode::<TemperatureCheckCreatedEvent>(data).unwrap())
+        .expect("TemperatureCheckCreatedEvent not emitted");
+    assert_eq!(event.rfc_reference, Some(rfc_reference));
+    assert_eq!(event.attachments, vec![attachment]);
+}
+
+#[test]
+fn test_attachments_are_validated() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (owner_badge, _owner_account, _owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let governance_component =
+        instantiate_governance(&mut ledger, owner_badge, create_governance_parameters());
+
+    let (author_pk, _author_sk, author_account) = ledger.new_allocated_account();
+    let attachment = Attachment {
+        name: "Budget breakdown".to_string(),
+        url: Url::of("https://example.com/budget.pdf"),
+        content_hash: hash("budget".as_bytes()),
+        media_type: "application/pdf".to_string(),
+    };
+
+    // Malformed media type
+    let mut draft = create_temp_check_draft();
+    draft.attachments = vec![Attachment {
+        media_type: "pdf".to_string(),
+        ..attachment.clone()
+    }];
+    assert!(!try_make_temperature_check(
+        &mut ledger,
+        governance_component,
+        author_account,
+        &author_pk,
+        draft,
+    ));
+
+    // Too many attachments
+    let mut draft = create_temp_check_draft();
+    draft.attachments = vec![attachment.clone(); MAX_ATTACHMENTS + 1];
+    assert!(!try_make_temperature_check(
+        &mut ledger,
+        governance_component,
+        author_account,
+        &author_pk,
+        draft,
+    ));
+
+    // Within limits
+    let mut draft = create_temp_check_draft();
+    draft.attachments = vec![attachment; MAX_ATTACHMENTS];
+    assert!(try_make_temperature_check(
+        &mut ledger,
+        governance_component,
+        author_account,
+        &author_pk,
+        draft,
+    ));
+}
+
+// =============================================================================
+// Vote Option Metadata Tests
+// =============================================================================
+
+#[test]
+fn test_abstain_option_is_appended_and_exclusive() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (owner_badge, owner_account, owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let mut params = create_governance_parameters();
+    params.abstain_option_label = Some("Abstain".to_string());
+    let governance_component = instantiate_governance(&mut ledger, owner_badge, params);
+
+    let (author_pk, _author_sk, author_account) = ledger.new_allocated_account();
+    let mut draft = create_multi_choice_temp_check_draft();
+    draft.vote_options[0] = ProposalVoteOptionInput {
+        label: "Option A".to_string(),
+        description: Some("Fund the full roadmap".to_string()),
+        color: Some("#00C389".to_string()),
+        link: Some(Url::of("https://radixtalk.com/proposal/456#option-a")),
+    };
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "make_temperature_check",
+            manifest_args!(author_account, draft),
+        )
+        .build();
+    let receipt = ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&author_pk)],
+    );
+
+    // The draft's options keep IDs 0..3 and abstain is appended as option 3
+    let event = receipt
+        .expect_commit_success()
+        .application_events
+        .iter()
+        .find(|(id, _)| ledger.event_name(id) == "TemperatureCheckCreatedEvent")
+        .map(|(_, data)| scrypto_decode::<TemperatureCheckCreatedEvent>(data).unwrap())
+        .expect("TemperatureCheckCreatedEvent not emitted");
+    assert_eq!(event.vote_options.len(), 4);
+    assert_eq!(event.vote_options[0].color, Some("#00C389".to_string()));
+    assert_eq!(event.vote_options[2].label, "Option C");
+    assert_eq!(event.vote_options[3].id, ProposalVoteOptionId(3));
+    assert!(event.vote_options[3].is_abstain);
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(owner_account, owner_badge, dec!(1))
+        .call_method(governance_component, "make_proposal", manifest_args!(0u64, None::<EligibilityRule>))
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&owner_pk)],
+        )
+        .expect_commit_success();
+
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+
+    // Abstain combined with another option is rejected
+    let votes: Vec<ProposalVoteOptionId> =
+        vec![ProposalVoteOptionId(0), ProposalVoteOptionId(3)];
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "vote_on_proposal",
+            manifest_args!(
+                voter_account,
+                0u64,
+                votes,
+                None::<VoteRationale>,
+                None::<ManifestProof>
+            ),
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&voter_pk)],
+        )
+        .expect_commit_failure();
+
+    // Abstain on its own is accepted
+    let votes: Vec<ProposalVoteOptionId> = vec![ProposalVoteOptionId(3)];
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "vote_on_proposal",
+            manifest_args!(
+                voter_account,
+                0u64,
+                votes,
+                None::<VoteRationale>,
+                None::<ManifestProof>
+            ),
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&voter_pk)],
+        )
+        .expect_commit_success();
+}
+
+#[test]
+fn test_vote_option_metadata_is_validated() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (owner_badge, _owner_account, _owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let mut params = create_governance_parameters();
+    params.abstain_option_label = Some("Abstain".to_string());
+    let governance_component = instantiate_governance(&mut ledger, owner_badge, params);
+
+    let (author_pk, _author_sk, author_account) = ledger.new_allocated_account();
+
+    // Color must be "#RRGGBB"
+    let mut draft = create_temp_check_draft();
+    draft.vote_options[0].color = Some("green".to_string());
+    assert!(!try_make_temperature_check(
+        &mut ledger,
+        governance_component,
+        author_account,
+        &author_pk,
+        draft,
+    ));
+
+    // Description over the 500 byte limit
+    let mut draft = create_temp_check_draft();
+    draft.vote_options[0].description = Some("D".repeat(501));
+    assert!(!try_make_temperature_check(
+        &mut ledger,
+        governance_component,
+        author_account,
+        &author_pk,
+        draft,
+    ));
+
+    // The abstain label is reserved
+    let mut draft = create_temp_check_draft();
+    draft.vote_options[1] = vote_option("Abstain");
+    assert!(!try_make_temperature_check(
+        &mut ledger,
+        governance_component,
+        author_account,
+        &author_pk,
+        draft,
+    ));
+}
+
+// =============================================================================
+// Minimum Selections Tests
+// =============================================================================
+
+#[test]
+fn test_min_selections_enforced_on_proposal_votes() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (owner_badge, owner_account, owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let governance_component =
+        instantiate_governance(&mut ledger, owner_badge, create_governance_parameters());
+
+    // "Pick exactly 2 of 3"
+    let (author_pk, _author_sk, author_account) = ledger.new_allocated_account();
+    let mut draft = create_multi_choice_temp_check_draft();
+    draft.min_selections = Some(2);
+    make_temperature_check(&mut ledger, governance_component, author_account, &author_pk, draft);
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(owner_account, owner_badge, dec!(1))
+        .call_method(governance_component, "make_proposal", manifest_args!(0u64, None::<EligibilityRule>))
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&owner_pk)],
+        )
+        .expect_commit_success();
+
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+
+    // One option is below the minimum
+    let votes: Vec<ProposalVoteOptionId> = vec![ProposalVoteOptionId(0)];
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "vote_on_proposal",
+            manifest_args!(
+                voter_account,
+                0u64,
+                votes,
+                None::<VoteRationale>,
+                None::<ManifestProof>
+            ),
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&voter_pk)],
+        )
+        .expect_specific_failure(|e| format!("{:?}", e).contains("Must select at least 2 options"));
+
+    // Two options satisfy it
+    let votes: Vec<ProposalVoteOptionId> =
+        vec![ProposalVoteOptionId(0), ProposalVoteOptionId(2)];
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "vote_on_proposal",
+            manifest_args!(
+                voter_account,
+                0u64,
+                votes,
+                None::<VoteRationale>,
+                None::<ManifestProof>
+            ),
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&voter_pk)],
+        )
+        .expect_commit_success();
+}
+
+#[test]
+fn test_min_selections_validated_on_draft() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (owner_badge, _owner_account, _owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let governance_component =
+        instantiate_governance(&mut ledger, owner_badge, create_governance_parameters());
+
+    let (author_pk, _author_sk, author_account) = ledger.new_allocated_account();
+
+    // Above max_selections
+    let mut draft = create_multi_choice_temp_check_draft();
+    draft.min_selections = Some(3);
+    assert!(!try_make_temperature_check(
+        &mut ledger,
+        governance_component,
+        author_account,
+        &author_pk,
+        draft,
+    ));
+
+    // Zero
+    let mut draft = create_multi_choice_temp_check_draft();
+    draft.min_selections = Some(0);
+    assert!(!try_make_temperature_check(
+        &mut ledger,
+        governance_component,
+        author_account,
+        &author_pk,
+        draft,
+    ));
+
+    // Single choice has no minimum to configure
+    let mut draft = create_temp_check_draft();
+    draft.min_selections = Some(1);
+    assert!(!try_make_temperature_check(
+        &mut ledger,
+        governance_component,
+        author_account,
+        &author_pk,
+        draft,
+    ));
+}
+
+// =============================================================================
+// View API Tests
+// =============================================================================
+
+#[test]
+fn test_view_getters_return_items_and_votes() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (owner_badge, _owner_account, _owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let governance_component =
+        instantiate_governance(&mut ledger, owner_badge, create_governance_parameters());
+
+    let (author_pk, _author_sk, author_account) = ledger.new_allocated_account();
+    make_temperature_check(
+        &mut ledger,
+        governance_component,
+        author_account,
+        &author_pk,
+        create_temp_check_draft(),
+    );
+
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+    let (second_pk, _second_sk, second_account) = ledger.new_allocated_account();
+    assert!(vote_on_first_temperature_check(
+        &mut ledger,
+        governance_component,
+        voter_account,
+        &voter_pk,
+        TemperatureCheckVote::For,
+    ));
+    assert!(vote_on_first_temperature_check(
+        &mut ledger,
+        governance_component,
+        second_account,
+        &second_pk,
+        TemperatureCheckVote::Against,
+    ));
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "get_temperature_check", manifest_args!(0u64))
+        .call_method(governance_component, "get_temperature_check", manifest_args!(1u64))
+        .call_method(governance_component, "get_temperature_checks", manifest_args!(0u64, 10u32))
+        .call_method(
+            governance_component,
+            "get_votes",
+            manifest_args!(ItemKind::TemperatureCheck, 0u64, 1u64, 10u32),
+        )
+        .build();
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let commit = receipt.expect_commit_success();
+
+    let view: Option<TemperatureCheckView> = commit.output(1);
+    let view = view.unwrap();
+    assert_eq!(view.id, 0);
+    assert_eq!(view.content.title, "Test Proposal");
+    assert_eq!(view.content.vote_options.len(), 2);
+    assert_eq!(view.vote_count, 2);
+    assert_eq!(view.elevated_proposal_id, None);
+
+    let missing: Option<TemperatureCheckView> = commit.output(2);
+    assert!(missing.is_none());
+
+    let page: Vec<TemperatureCheckView> = commit.output(3);
+    assert_eq!(page.len(), 1);
+
+    // Starting at vote ID 1 returns only the second vote
+    let votes: Vec<VoteView> = commit.output(4);
+    assert_eq!(votes.len(), 1);
+    assert_eq!(votes[0].vote_id, 1);
+    match &votes[0].record {
+        VoteRecord::TemperatureCheck(record) => {
+            assert_eq!(record.vote, Some(TemperatureCheckVote::Against));
+        }
+        VoteRecord::Proposal(_) => panic!("Expected a temperature check vote"),
+    }
+}
+
+#[test]
+fn test_view_getters_reject_oversized_pages() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (owner_badge, _owner_account, _owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let governance_component =
+        instantiate_governance(&mut ledger, owner_badge, create_governance_parameters());
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "get_proposals",
+            manifest_args!(0u64, MAX_PAGE_SIZE + 1),
+        )
+        .build();
+    ledger
+        .execute_manifest(manifest, vec![])
+        .expect_commit_failure();
+}
+
+// =============================================================================
+// Account Vote Index Tests
+// =============================================================================
+
+#[test]
+fn test_account_vote_index_tracks_votes_and_retractions() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (owner_badge, _owner_account, _owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let mut params = create_governance_parameters();
+    params.revote_policy = RevotePolicy::Unlimited;
+    let governance_component = instantiate_governance(&mut ledger, owner_badge, params);
+
+    let (author_pk, _author_sk, author_account) = ledger.new_allocated_account();
+    for _ in 0..2 {
+        make_temperature_check(
+            &mut ledger,
+            governance_component,
+            author_account,
+            &author_pk,
+            create_temp_check_draft(),
+        );
+    }
+
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+    let (other_pk, _other_sk, other_account) = ledger.new_allocated_account();
+
+    // Another account's vote takes vote ID 0 on temperature check 1
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "vote_on_temperature_check",
+            manifest_args!(
+                other_account,
+                1u64,
+                TemperatureCheckVote::For,
+                None::<VoteRationale>,
+                None::<ManifestProof>
+            ),
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&other_pk)],
+        )
+        .expect_commit_success();
+
+    // The voter votes on temperature check 0, then 1, then retracts on 0
+    assert!(vote_on_first_temperature_check(
+        &mut ledger,
+        governance_component,
+        voter_account,
+        &voter_pk,
+        TemperatureCheckVote::For,
+    ));
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "vote_on_temperature_check",
+            manifest_args!(
+                voter_account,
+                1u64,
+                TemperatureCheckVote::Against,
+                None::<VoteRationale>,
+                None::<ManifestProof>
+            ),
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&voter_pk)],
+        )
+        .expect_commit_success();
+    assert!(retract_first_temperature_check_vote(
+        &mut ledger,
+        governance_component,
+        voter_account,
+        &voter_pk,
+    ));
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "get_account_vote",
+            manifest_args!(ItemKind::TemperatureCheck, 1u64, voter_account),
+        )
+        .call_method(
+            governance_component,
+            "get_account_vote",
+            manifest_args!(ItemKind::Proposal, 0u64, voter_account),
+        )
+        .call_method(
+            governance_component,
+            "get_account_vote_history",
+            manifest_args!(voter_account, 0u64, 10u32),
+        )
+        .build();
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let commit = receipt.expect_commit_success();
+
+    let vote: Option<AccountVote> = commit.output(1);
+    assert_eq!(
+        vote,
+        Some(AccountVote {
+            item_kind: ItemKind::TemperatureCheck,
+            item_id: 1,
+            vote_id: 1,
+            retracted: false,
+        })
+    );
+
+    let vote: Option<AccountVote> = commit.output(2);
+    assert!(vote.is_none());
+
+    // History is ordered by first vote; the retraction updates the existing entry
+    let history: Vec<AccountVote> = commit.output(3);
+    assert_eq!(
+        history,
+        vec![
+            AccountVote {
+                item_kind: ItemKind::TemperatureCheck,
+                item_id: 0,
+                vote_id: 1,
+                retracted: true,
+            },
+            AccountVote {
+                item_kind: ItemKind::TemperatureCheck,
+                item_id: 1,
+                vote_id: 1,
+                retracted: false,
+            },
+        ]
+    );
+}
+
+// =============================================================================
+// Author Index Tests
+// =============================================================================
+
+#[test]
+fn test_author_index_and_stats() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (owner_badge, owner_account, owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let governance_component =
+        instantiate_governance(&mut ledger, owner_badge, create_governance_parameters());
+
+    let (author_pk, _author_sk, author_account) = ledger.new_allocated_account();
+    for _ in 0..2 {
+        make_temperature_check(
+            &mut ledger,
+            governance_component,
+            author_account,
+            &author_pk,
+            create_temp_check_draft(),
+        );
+    }
+
+    // The author withdraws temperature check 1
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "withdraw_temperature_check",
+            manifest_args!(author_account, 1u64),
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&author_pk)],
+        )
+        .expect_commit_success();
+
+    // The owner elevates temperature check 0 and hides the resulting proposal
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(owner_account, owner_badge, dec!(1))
+        .call_method(governance_component, "make_proposal", manifest_args!(0u64, None::<EligibilityRule>))
+        .call_method(governance_component, "toggle_proposal_hidden", manifest_args!(0u64))
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&owner_pk)],
+        )
+        .expect_commit_success();
+
+    let (_other_pk, _other_sk, other_account) = ledger.new_allocated_account();
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "get_author_stats", manifest_args!(author_account))
+        .call_method(
+            governance_component,
+            "get_author_items",
+            manifest_args!(author_account, 0u64, 10u32),
+        )
+        .call_method(governance_component, "get_author_stats", manifest_args!(other_account))
+        .build();
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let commit = receipt.expect_commit_success();
+
+    let stats: AuthorStats = commit.output(1);
+    assert_eq!(
+        stats,
+        AuthorStats {
+            created: 2,
+            elevated: 1,
+            hidden: 1,
+            withdrawn: 1,
+        }
+    );
+
+    let items: Vec<AuthorItem> = commit.output(2);
+    assert_eq!(
+        items,
+        vec![
+            AuthorItem {
+                temperature_check_id: 0,
+                proposal_id: Some(0),
+            },
+            AuthorItem {
+                temperature_check_id: 1,
+                proposal_id: None,
+            },
+        ]
+    );
+
+    let stats: AuthorStats = commit.output(3);
+    assert_eq!(stats, AuthorStats::default());
+}
+
+// =============================================================================
+// Tally Tests
+// =============================================================================
+
+#[test]
+fn test_tallies_follow_revotes_and_retractions() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (owner_badge, owner_account, owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let mut params = create_governance_parameters();
+    params.revote_policy = RevotePolicy::Unlimited;
+    let governance_component = instantiate_governance(&mut ledger, owner_badge, params);
+
+    let (author_pk, _author_sk, author_account) = ledger.new_allocated_account();
+    make_temperature_check(
+        &mut ledger,
+        governance_component,
+        author_account,
+        &author_pk,
+        create_multi_choice_temp_check_draft(),
+    );
+
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+    let (second_pk, _second_sk, second_account) = ledger.new_allocated_account();
+
+    // Both vote For, the voter switches to Against and the second account retracts
+    for (account, pk, vote) in [
+        (voter_account, &voter_pk, TemperatureCheckVote::For),
+        (second_account, &second_pk, TemperatureCheckVote::For),
+        (voter_account, &voter_pk, TemperatureCheckVote::Against),
+    ] {
+        assert!(vote_on_first_temperature_check(
+            &mut ledger,
+            governance_component,
+            account,
+            pk,
+            vote,
+        ));
+    }
+    assert!(retract_first_temperature_check_vote(
+        &mut ledger,
+        governance_component,
+        second_account,
+        &second_pk,
+    ));
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(owner_account, owner_badge, dec!(1))
+        .call_method(governance_component, "make_proposal", manifest_args!(0u64, None::<EligibilityRule>))
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&owner_pk)],
+        )
+        .expect_commit_success();
+
+    // The voter selects options 0 and 1, then changes to 1 and 2
+    for votes in [
+        vec![ProposalVoteOptionId(0), ProposalVoteOptionId(1)],
+        vec![ProposalVoteOptionId(1), ProposalVoteOptionId(2)],
+    ] {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                governance_component,
+                "vote_on_proposal",
+                manifest_args!(
+                    voter_account,
+                    0u64,
+                    votes,
+                    None::<VoteRationale>,
+                    None::<ManifestProof>
+                ),
+            )
+            .build();
+        ledger
+            .execute_manifest(
+                manifest,
+                vec![NonFungibleGlobalId::from_public_key(&voter_pk)],
+            )
+            .expect_commit_success();
+    }
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "get_temperature_check_tally",
+            manifest_args!(0u64),
+        )
+        .call_method(governance_component, "get_proposal_tally", manifest_args!(0u64))
+        .build();
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let commit = receipt.expect_commit_success();
+
+    let tally: TemperatureCheckTally = commit.output(1);
+    assert_eq!(
+        tally,
+        TemperatureCheckTally {
+            for_votes: 0,
+            against_votes: 1,
+        }
+    );
+
+    let option_tallies: Vec<u64> = commit.output(2);
+    assert_eq!(option_tallies, vec![0, 1, 1]);
+}
+
+// =============================================================================
+// Merkle Weighted Voting Tests
+// =============================================================================
+
+/// Votes on temperature check 0 with a Merkle power proof and returns whether it was accepted
+fn vote_with_power_on_first_temperature_check(
+    ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>,
+    governance_component: ComponentAddress,
+    voter_account: ComponentAddress,
+    voter_pk: &Secp256k1PublicKey,
+    vote: TemperatureCheckVote,
+    power_proof: MerklePowerProof,
+) -> bool {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "vote_on_temperature_check_with_power",
+            manifest_args!(
+                voter_account,
+                0u64,
+                vote,
+                None::<VoteRationale>,
+                power_proof,
+                None::<ManifestProof>
+            ),
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(voter_pk)],
+        )
+        .is_commit_success()
+}
+
+#[test]
+fn test_merkle_weighted_temperature_check() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (owner_badge, owner_account, owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let mut params = create_governance_parameters();
+    params.power_mode = PowerMode::MerkleSnapshot;
+    params.temperature_check_quorum = dec!(400);
+    let governance_component = instantiate_governance(&mut ledger, owner_badge, params);
+
+    let (author_pk, _author_sk, author_account) = ledger.new_allocated_account();
+    make_temperature_check(
+        &mut ledger,
+        governance_component,
+        author_account,
+        &author_pk,
+        create_temp_check_draft(),
+    );
+
+    let (a_pk, _a_sk, a_account) = ledger.new_allocated_account();
+    let (b_pk, _b_sk, b_account) = ledger.new_allocated_account();
+    let (c_pk, _c_sk, c_account) = ledger.new_allocated_account();
+
+    // Snapshot tree: root = parent(parent(a, b), c)
+    let leaf_a = power_leaf(a_account, dec!(100));
+    let leaf_b = power_leaf(b_account, dec!(300));
+    let leaf_c = power_leaf(c_account, dec!(50));
+    let node_ab = merkle_parent(leaf_a, leaf_b);
+    let root = merkle_parent(node_ab, leaf_c);
+
+    let proof_a = MerklePowerProof {
+        power: dec!(100),
+        siblings: vec![leaf_b, leaf_c],
+    };
+    let proof_b = MerklePowerProof {
+        power: dec!(300),
+        siblings: vec![leaf_a, leaf_c],
+    };
+    let proof_c = MerklePowerProof {
+        power: dec!(50),
+        siblings: vec![node_ab],
+    };
+
+    // No votes before the attestor posts the snapshot root
+    assert!(!vote_with_power_on_first_temperature_check(
+        &mut ledger,
+        governance_component,
+        a_account,
+        &a_pk,
+        TemperatureCheckVote::For,
+        proof_a.clone(),
+    ));
+
+    // The attestor role defaults to the owner badge
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(owner_account, owner_badge, dec!(1))
+        .call_method(
+            governance_component,
+            "post_power_root",
+            manifest_args!(ItemKind::TemperatureCheck, 0u64, root),
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&owner_pk)],
+        )
+        .expect_commit_success();
+
+    // Unweighted ballots are rejected on a weighted item
+    assert!(!vote_on_first_temperature_check(
+        &mut ledger,
+        governance_component,
+        a_account,
+        &a_pk,
+        TemperatureCheckVote::For,
+    ));
+
+    // An inflated power claim does not match the snapshot
+    assert!(!vote_with_power_on_first_temperature_check(
+        &mut ledger,
+        governance_component,
+        b_account,
+        &b_pk,
+        TemperatureCheckVote::Against,
+        MerklePowerProof {
+            power: dec!(3000),
+            ..proof_b.clone()
+        },
+    ));
+
+    for (account, pk, vote, proof) in [
+        (a_account, &a_pk, TemperatureCheckVote::For, proof_a),
+        (b_account, &b_pk, TemperatureCheckVote::Against, proof_b),
+        (c_account, &c_pk, TemperatureCheckVote::For, proof_c),
+    ] {
+        assert!(vote_with_power_on_first_temperature_check(
+            &mut ledger,
+            governance_component,
+            account,
+            pk,
+            vote,
+            proof,
+        ));
+    }
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "get_temperature_check_result",
+            manifest_args!(0u64),
+        )
+        .build();
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let result: TemperatureCheckResult = receipt.expect_commit_success().output(1);
+    assert_eq!(
+        result.power_tally,
+        TemperatureCheckPowerTally {
+            for_power: dec!(150),
+            against_power: dec!(300),
+        }
+    );
+    assert_eq!(result.total_power, dec!(450));
+    assert!(result.quorum_reached);
+    assert!(!result.approved);
+}
+
+#[test]
+fn test_power_root_is_attestor_only_and_set_once() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (owner_badge, owner_account, owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let mut params = create_governance_parameters();
+    params.power_mode = PowerMode::MerkleSnapshot;
+    let governance_component = instantiate_governance(&mut ledger, owner_badge, params);
+
+    let (author_pk, _author_sk, author_account) = ledger.new_allocated_account();
+    make_temperature_check(
+        &mut ledger,
+        governance_component,
+        author_account,
+        &author_pk,
+        create_temp_check_draft(),
+    );
+
+    let root = hash("snapshot".as_bytes());
+
+    // Without the attestor badge
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "post_power_root",
+            manifest_args!(ItemKind::TemperatureCheck, 0u64, root),
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&author_pk)],
+        )
+        .expect_commit_failure();
+
+    // The root can only be posted once
+    for expect_success in [true, false] {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_amount(owner_account, owner_badge, dec!(1))
+            .call_method(
+                governance_component,
+                "post_power_root",
+                manifest_args!(ItemKind::TemperatureCheck, 0u64, root),
+            )
+            .build();
+        let receipt = ledger.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&owner_pk)],
+        );
+        assert_eq!(receipt.is_commit_success(), expect_success);
+    }
+}
+
+#[test]
+fn test_owner_can_reassign_attestor() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (owner_badge, owner_account, owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let mut params = create_governance_parameters();
+    params.power_mode = PowerMode::MerkleSnapshot;
+    let governance_component = instantiate_governance(&mut ledger, owner_badge, params);
+
+    let (author_pk, _author_sk, author_account) = ledger.new_allocated_account();
+    make_temperature_check(
+        &mut ledger,
+        governance_component,
+        author_account,
+        &author_pk,
+        create_temp_check_draft(),
+    );
+
+    // Hand the attestor role to a separate badge
+    let (attestor_pk, _attestor_sk, attestor_account) = ledger.new_allocated_account();
+    let attestor_badge = ledger.create_non_fungible_resource(attestor_account);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(owner_account, owner_badge, dec!(1))
+        .call_method(
+            governance_component,
+            "set_attestor_badge",
+            manifest_args!(attestor_badge),
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&owner_pk)],
+        )
+        .expect_commit_success();
+
+    let root = hash("snapshot".as_bytes());
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(owner_account, owner_badge, dec!(1))
+        .call_method(
+            governance_component,
+            "post_power_root",
+            manifest_args!(ItemKind::TemperatureCheck, 0u64, root),
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&owner_pk)],
+        )
+        .expect_commit_failure();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_non_fungibles(
+            attestor_account,
+            attestor_badge,
+            [NonFungibleLocalId::integer(1)],
+        )
+        .call_method(
+            governance_component,
+            "post_power_root",
+            manifest_args!(ItemKind::TemperatureCheck, 0u64, root),
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&attestor_pk)],
+        )
+        .expect_commit_success();
+}
+
+// =============================================================================
+// LSU Weighted Voting Tests
+// =============================================================================
+
+/// Votes on temperature check 0 with a proof of `amount` of `lsu` and returns the receipt
+fn vote_with_lsus_on_first_temperature_check(
+    ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>,
+    governance_component: ComponentAddress,
+    voter_account: ComponentAddress,
+    voter_pk: &Secp256k1PublicKey,
+    vote: TemperatureCheckVote,
+    lsu: ResourceAddress,
+    amount: Decimal,
+) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(voter_account, lsu, amount)
+        .pop_from_auth_zone("lsu_proof")
+        .call_method_with_name_lookup(
+            governance_component,
+            "vote_on_temperature_check_with_lsus",
+            |lookup| {
+                (
+                    voter_account,
+                    0u64,
+                    vote,
+                    None::<VoteRationale>,
+                    vec![lookup.proof("lsu_proof")],
+                    None::<ManifestProof>,
+                )
+            },
+        )
+        .build();
+    ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(voter_pk)],
+    )
+}
+
+#[test]
+fn test_lsu_weighted_temperature_check() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (owner_badge, _owner_account, _owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let mut params = create_governance_parameters();
+    params.power_mode = PowerMode::LsuProofs;
+    let governance_component = instantiate_governance(&mut ledger, owner_badge, params);
+
+    let (author_pk, _author_sk, author_account) = ledger.new_allocated_account();
+    make_temperature_check(
+        &mut ledger,
+        governance_component,
+        author_account,
+        &author_pk,
+        create_temp_check_draft(),
+    );
+
+    // The staker owns a validator and holds its stake units
+    let (staker_pk, _staker_sk, staker_account) = ledger.new_allocated_account();
+    let validator = ledger.new_staked_validator_with_pub_key(staker_pk, staker_account);
+    let lsu = ledger.get_validator_info(validator).stake_unit_resource;
+    let staked = ledger.get_component_balance(staker_account, lsu);
+    assert!(staked > Decimal::ZERO);
+
+    // Unweighted ballots are rejected on an LSU-weighted item
+    assert!(!vote_on_first_temperature_check(
+        &mut ledger,
+        governance_component,
+        staker_account,
+        &staker_pk,
+        TemperatureCheckVote::For,
+    ));
+
+    vote_with_lsus_on_first_temperature_check(
+        &mut ledger,
+        governance_component,
+        staker_account,
+        &staker_pk,
+        TemperatureCheckVote::For,
+        lsu,
+        staked,
+    )
+    .expect_commit_success();
+
+    // Without any rewards the stake units redeem 1:1 for XRD
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "get_temperature_check",
+            manifest_args!(0u64),
+        )
+        .build();
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let view: Option<TemperatureCheckView> = receipt.expect_commit_success().output(1);
+    assert_eq!(view.unwrap().power_tally.for_power, staked);
+
+    // A look-alike token pointing at the validator is not one of its stake units
+    let (faker_pk, _faker_sk, faker_account) = ledger.new_allocated_account();
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_fungible_resource(
+            OwnerRole::None,
+            false,
+            18,
+            FungibleResourceRoles::default(),
+            metadata!(
+                init {
+                    "validator" => GlobalAddress::from(validator), locked;
+                }
+            ),
+            Some(dec!(1000000)),
+        )
+        .try_deposit_entire_worktop_or_abort(faker_account, None)
+        .build();
+    let receipt = ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&faker_pk)],
+    );
+    let fake_lsu = receipt.expect_commit(true).new_resource_addresses()[0];
+
+    vote_with_lsus_on_first_temperature_check(
+        &mut ledger,
+        governance_component,
+        faker_account,
+        &faker_pk,
+        TemperatureCheckVote::Against,
+        fake_lsu,
+        dec!(1000000),
+    )
+    .expect_specific_failure(|e| {
+        format!("{:?}", e).contains("Resource is not a validator stake unit")
+    });
+}
+
+// =============================================================================
+// Vote-Escrow Tests
+// =============================================================================
+
+/// Votes on temperature check 0 backed by vote-escrow positions and returns the receipt
+fn vote_with_position_on_first_temperature_check(
+    ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>,
+    governance_component: ComponentAddress,
+    voter_account: ComponentAddress,
+    voter_pk: &Secp256k1PublicKey,
+    position_resource: ResourceAddress,
+    position_ids: &[u64],
+) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_non_fungibles(
+            voter_account,
+            position_resource,
+            position_ids.iter().map(|id| NonFungibleLocalId::integer(*id)),
+        )
+        .pop_from_auth_zone("position_proof")
+        .call_method_with_name_lookup(
+            governance_component,
+            "vote_on_temperature_check_with_position",
+            |lookup| {
+                (
+                    voter_account,
+                    0u64,
+                    TemperatureCheckVote::For,
+                    None::<VoteRationale>,
+                    vec![lookup.proof("position_proof")],
+                    None::<ManifestProof>,
+                )
+            },
+        )
+        .build();
+    ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(voter_pk)],
+    )
+}
+
+/// Withdraws vote-escrow position 0 into `account` and returns the receipt
+fn withdraw_first_escrow_position(
+    ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>,
+    governance_component: ComponentAddress,
+    account: ComponentAddress,
+    pk: &Secp256k1PublicKey,
+    position_resource: ResourceAddress,
+) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_non_fungibles_from_account(
+            account,
+            position_resource,
+            [NonFungibleLocalId::integer(0)],
+        )
+        .take_all_from_worktop(position_resource, "position")
+        .call_method_with_name_lookup(
+            governance_component,
+            "withdraw_escrow_position",
+            |lookup| (lookup.bucket("position"),),
+        )
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(pk)],
+    )
+}
+
+/// Locks `amount` XRD from `account` into a new vote-escrow position for `lock_days`
+fn lock_xrd_escrow_position(
+    ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>,
+    governance_component: ComponentAddress,
+    account: ComponentAddress,
+    pk: &Secp256k1PublicKey,
+    amount: Decimal,
+    lock_days: u32,
+) {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(account, XRD, amount)
+        .take_all_from_worktop(XRD, "xrd")
+        .call_method_with_name_lookup(
+            governance_component,
+            "lock_escrow_position",
+            |lookup| (lookup.bucket("xrd"), lock_days),
+        )
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(pk)],
+        )
+        .expect_commit_success();
+}
+
+#[test]
+fn test_vote_escrow_position_voting_and_withdrawal() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (owner_badge, _owner_account, _owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let mut params = create_governance_parameters();
+    params.power_mode = PowerMode::VoteEscrow;
+    let governance_component = instantiate_governance(&mut ledger, owner_badge, params);
+    let now = ledger.get_current_time(TimePrecision::Second);
+
+    let (author_pk, _author_sk, author_account) = ledger.new_allocated_account();
+    make_temperature_check(
+        &mut ledger,
+        governance_component,
+        author_account,
+        &author_pk,
+        create_temp_check_draft(),
+    );
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "get_escrow_position_resource",
+            manifest_args!(),
+        )
+        .build();
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let position_resource: ResourceAddress = receipt.expect_commit_success().output(1);
+
+    // Lock 1460 XRD for one day: 1/1460 of the maximum lock, so a power of 1
+    let (a_pk, _a_sk, a_account) = ledger.new_allocated_account();
+    lock_xrd_escrow_position(
+        &mut ledger,
+        governance_component,
+        a_account,
+        &a_pk,
+        dec!(1460),
+        1,
+    );
+
+    // Ballots without a position are rejected on a vote-escrow item
+    assert!(!vote_on_first_temperature_check(
+        &mut ledger,
+        governance_component,
+        a_account,
+        &a_pk,
+        TemperatureCheckVote::For,
+    ));
+
+    vote_with_position_on_first_temperature_check(
+        &mut ledger,
+        governance_component,
+        a_account,
+        &a_pk,
+        position_resource,
+        &[0],
+    )
+    .expect_commit_success();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "get_temperature_check",
+            manifest_args!(0u64),
+        )
+        .build();
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let view: Option<TemperatureCheckView> = receipt.expect_commit_success().output(1);
+    assert_eq!(view.unwrap().power_tally.for_power, dec!(1));
+
+    // Moving the position to another account does not let it vote again
+    let (b_pk, _b_sk, b_account) = ledger.new_allocated_account();
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_non_fungibles_from_account(
+            a_account,
+            position_resource,
+            [NonFungibleLocalId::integer(0)],
+        )
+        .try_deposit_entire_worktop_or_abort(b_account, None)
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&a_pk)],
+        )
+        .expect_commit_success();
+
+    vote_with_position_on_first_temperature_check(
+        &mut ledger,
+        governance_component,
+        b_account,
+        &b_pk,
+        position_resource,
+        &[0],
+    )
+    .expect_specific_failure(|e| {
+        format!("{:?}", e).contains("Vote-escrow position has already backed another account's vote")
+    });
+
+    // The lock has expired, but the temperature check it voted on is still open
+    ledger.set_current_time(now.add_days(2).unwrap().seconds_since_unix_epoch * 1000);
+    withdraw_first_escrow_position(
+        &mut ledger,
+        governance_component,
+        b_account,
+        &b_pk,
+        position_resource,
+    )
+    .expect_specific_failure(|e| {
+        format!("{:?}", e).contains("Vote-escrow position has voted on an item that is still open")
+    });
+
+    // Once the temperature check has closed the tokens can be withdrawn
+    ledger.set_current_time(now.add_days(8).unwrap().seconds_since_unix_epoch * 1000);
+    let balance_before = ledger.get_component_balance(b_account, XRD);
+    withdraw_first_escrow_position(
+        &mut ledger,
+        governance_component,
+        b_account,
+        &b_pk,
+        position_resource,
+    )
+    .expect_commit_success();
+    assert_eq!(
+        ledger.get_component_balance(b_account, XRD),
+        balance_before + dec!(1460)
+    );
+}
+
+#[test]
+fn test_vote_escrow_ballot_sums_positions() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (owner_badge, _owner_account, _owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let mut params = create_governance_parameters();
+    params.power_mode = PowerMode::VoteEscrow;
+    let governance_component = instantiate_governance(&mut ledger, owner_badge, params);
+
+    let (author_pk, _author_sk, author_account) = ledger.new_allocated_account();
+    make_temperature_check(
+        &mut ledger,
+        governance_component,
+        author_account,
+        &author_pk,
+        create_temp_check_draft(),
+    );
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "get_escrow_position_resource",
+            manifest_args!(),
+        )
+        .build();
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let position_resource: ResourceAddress = receipt.expect_commit_success().output(1);
+
+    // Two one-day positions with powers of 1 and 2
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+    for amount in [dec!(1460), dec!(2920)] {
+        lock_xrd_escrow_position(
+            &mut ledger,
+            governance_component,
+            voter_account,
+            &voter_pk,
+            amount,
+            1,
+        );
+    }
+
+    // The same position cannot be counted twice
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_non_fungibles(
+            voter_account,
+            position_resource,
+            [NonFungibleLocalId::integer(0)],
+        )
+        .pop_from_auth_zone("first_proof")
+        .create_proof_from_account_of_non_fungibles(
+            voter_account,
+            position_resource,
+            [NonFungibleLocalId::integer(0)],
+        )
+        .pop_from_auth_zone("second_proof")
+        .call_method_with_name_lookup(
+            governance_component,
+            "vote_on_temperature_check_with_position",
+            |lookup| {
+                (
+                    voter_account,
+                    0u64,
+                    TemperatureCheckVote::For,
+                    None::<VoteRationale>,
+                    vec![lookup.proof("first_proof"), lookup.proof("second_proof")],
+                    None::<ManifestProof>,
+                )
+            },
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&voter_pk)],
+        )
+        .expect_specific_failure(|e| format!("{:?}", e).contains("Duplicate vote-escrow position"));
+
+    // Both positions back one ballot and their powers are added
+    vote_with_position_on_first_temperature_check(
+        &mut ledger,
+        governance_component,
+        voter_account,
+        &voter_pk,
+        position_resource,
+        &[0, 1],
+    )
+    .expect_commit_success();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "get_temperature_check",
+            manifest_args!(0u64),
+        )
+        .build();
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let view: Option<TemperatureCheckView> = receipt.expect_commit_success().output(1);
+    assert_eq!(view.unwrap().power_tally.for_power, dec!(3));
+}
+
+// =============================================================================
+// Power Source Registry Tests
+// =============================================================================
+
+#[test]
+fn test_items_snapshot_power_source_registry() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (owner_badge, owner_account, owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let params = create_governance_parameters();
+    let governance_component = instantiate_governance(&mut ledger, owner_badge, params);
+
+    let xrd_source = PowerSource {
+        address: GlobalAddress::from(XRD),
+        kind: PowerSourceKind::Xrd,
+        weight: dec!(1),
+    };
+    let pool_source = PowerSource {
+        address: GlobalAddress::from(owner_badge),
+        kind: PowerSourceKind::PoolUnit,
+        weight: dec!("0.5"),
+    };
+
+    // Only the owner can change the registry
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "set_power_source",
+            manifest_args!(xrd_source.clone()),
+        )
+        .build();
+    ledger
+        .execute_manifest(manifest, vec![])
+        .expect_commit_failure();
+
+    for source in [xrd_source.clone(), pool_source.clone()] {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_amount(owner_account, owner_badge, dec!(1))
+            .call_method(governance_component, "set_power_source", manifest_args!(source))
+            .build();
+        ledger
+            .execute_manifest(
+                manifest,
+                vec![NonFungibleGlobalId::from_public_key(&owner_pk)],
+            )
+            .expect_commit_success();
+    }
+
+    let (author_pk, _author_sk, author_account) = ledger.new_allocated_account();
+    make_temperature_check(
+        &mut ledger,
+        governance_component,
+        author_account,
+        &author_pk,
+        create_temp_check_draft(),
+    );
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(owner_account, owner_badge, dec!(1))
+        .call_method(
+            governance_component,
+            "remove_power_source",
+            manifest_args!(GlobalAddress::from(owner_badge)),
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&owner_pk)],
+        )
+        .expect_commit_success();
+
+    make_temperature_check(
+        &mut ledger,
+        governance_component,
+        author_account,
+        &author_pk,
+        create_temp_check_draft(),
+    );
+
+    // The first temperature check keeps the registry it was created with
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "get_item_power_sources",
+            manifest_args!(ItemKind::TemperatureCheck, 0u64),
+        )
+        .call_method(
+            governance_component,
+            "get_item_power_sources",
+            manifest_args!(ItemKind::TemperatureCheck, 1u64),
+        )
+        .call_method(governance_component, "get_power_sources", manifest_args!())
+        .build();
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let commit = receipt.expect_commit_success();
+    let first: Vec<PowerSource> = commit.output(1);
+    let second: Vec<PowerSource> = commit.output(2);
+    let current: Vec<PowerSource> = commit.output(3);
+    assert_eq!(first, vec![xrd_source.clone(), pool_source]);
+    assert_eq!(second, vec![xrd_source.clone()]);
+    assert_eq!(current, vec![xrd_source]);
+}
+
+// =============================================================================
+// Ledger Anchor Tests
+// =============================================================================
+
+#[test]
+fn test_items_record_creation_epoch() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (owner_badge, owner_account, owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let governance_component =
+        instantiate_governance(&mut ledger, owner_badge, create_governance_parameters());
+
+    ledger.set_current_epoch(Epoch::of(42));
+    let (author_pk, _author_sk, author_account) = ledger.new_allocated_account();
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "make_temperature_check",
+            manifest_args!(author_account, create_temp_check_draft()),
+        )
+        .build();
+    let receipt = ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&author_pk)],
+    );
+    let event = receipt
+        .expect_commit_success()
+        .application_events
+        .iter()
+        .find(|(id, _)| ledger.event_name(id) == "TemperatureCheckCreatedEvent")
+        .map(|(_, data)| scrypto_decode::<TemperatureCheckCreatedEvent>(data).unwrap())
+        .expect("TemperatureCheckCreatedEvent not emitted");
+    assert_eq!(event.created_epoch, Epoch::of(42));
+
+    // The proposal is anchored to the epoch of its elevation
+    ledger.set_current_epoch(Epoch::of(50));
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(owner_account, owner_badge, dec!(1))
+        .call_method(governance_component, "make_proposal", manifest_args!(0u64, None::<EligibilityRule>))
+        .build();
+    let receipt = ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&owner_pk)],
+    );
+    let proposal_event = receipt
+        .expect_commit_success()
+        .application_events
+        .iter()
+        .find(|(id, _)| ledger.event_name(id) == "ProposalCreatedEvent")
+        .map(|(_, data)| scrypto_decode::<ProposalCreatedEvent>(data).unwrap())
+        .expect("ProposalCreatedEvent not emitted");
+    assert_eq!(proposal_event.created_epoch, Epoch::of(50));
+    assert_ne!(
+        proposal_event.created_transaction_hash,
+        event.created_transaction_hash
+    );
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "get_temperature_check",
+            manifest_args!(0u64),
+        )
+        .build();
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let view: Option<TemperatureCheckView> = receipt.expect_commit_success().output(1);
+    let view = view.unwrap();
+    assert_eq!(view.created_epoch, Epoch::of(42));
+    assert_eq!(view.created_transaction_hash, event.created_transaction_hash);
+}
+
+// =============================================================================
+// Eligibility Tests
+// =============================================================================
+
+/// Votes "For" on temperature check 0 with a proof of one eligibility badge
+/// Returns the receipt
+fn vote_with_badge_on_first_temperature_check(
+    ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>,
+    governance_component: ComponentAddress,
+    voter_account: ComponentAddress,
+    voter_pk: &Secp256k1PublicKey,
+    badge: ResourceAddress,
+    badge_id: u64,
+) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_non_fungibles(
+            voter_account,
+            badge,
+            [NonFungibleLocalId::integer(badge_id)],
+        )
+        .pop_from_auth_zone("eligibility_proof")
+        .call_method_with_name_lookup(
+            governance_component,
+            "vote_on_temperature_check",
+            |lookup| {
+                (
+                    voter_account,
+                    0u64,
+                    TemperatureCheckVote::For,
+                    None::<VoteRationale>,
+                    Some(lookup.proof("eligibility_proof")),
+                )
+            },
+        )
+        .build();
+    ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(voter_pk)],
+    )
+}
+
+#[test]
+fn test_eligibility_gated_voting() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (owner_badge, owner_account, owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let governance_component =
+        instantiate_governance(&mut ledger, owner_badge, create_governance_parameters());
+
+    // Council NFTs #1, #2 and #3 are held by the member account
+    let (member_pk, _member_sk, member_account) = ledger.new_allocated_account();
+    let council = ledger.create_non_fungible_resource(member_account);
+    let (outsider_pk, _outsider_sk, outsider_account) = ledger.new_allocated_account();
+
+    let eligibility = EligibilityRule::Resource {
+        resource: council,
+        min_amount: dec!(1),
+    };
+    let mut draft = create_temp_check_draft();
+    draft.eligibility = Some(eligibility.clone());
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "make_temperature_check",
+            manifest_args!(member_account, draft),
+        )
+        .build();
+    let receipt = ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&member_pk)],
+    );
+    let event = receipt
+        .expect_commit_success()
+        .application_events
+        .iter()
+        .find(|(id, _)| ledger.event_name(id) == "TemperatureCheckCreatedEvent")
+        .map(|(_, data)| scrypto_decode::<TemperatureCheckCreatedEvent>(data).unwrap())
+        .expect("TemperatureCheckCreatedEvent not emitted");
+    assert_eq!(event.eligibility, Some(eligibility));
+
+    // Votes without an eligibility proof are rejected, even from holders
+    assert!(!vote_on_first_temperature_check(
+        &mut ledger,
+        governance_component,
+        outsider_account,
+        &outsider_pk,
+        TemperatureCheckVote::For,
+    ));
+    assert!(!vote_on_first_temperature_check(
+        &mut ledger,
+        governance_component,
+        member_account,
+        &member_pk,
+        TemperatureCheckVote::For,
+    ));
+
+    vote_with_badge_on_first_temperature_check(
+        &mut ledger,
+        governance_component,
+        member_account,
+        &member_pk,
+        council,
+        1,
+    )
+    .expect_commit_success();
+
+    // Moving the badge to another account does not let it vote again
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_non_fungibles_from_account(
+            member_account,
+            council,
+            [NonFungibleLocalId::integer(1)],
+        )
+        .try_deposit_entire_worktop_or_abort(outsider_account, None)
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&member_pk)],
+        )
+        .expect_commit_success();
+
+    vote_with_badge_on_first_temperature_check(
+        &mut ledger,
+        governance_component,
+        outsider_account,
+        &outsider_pk,
+        council,
+        1,
+    )
+    .expect_specific_failure(|e| {
+        format!("{:?}", e).contains("Eligibility badge has already been used by another account")
+    });
+
+    // The proposal can narrow the electorate to a single NFT
+    let chair = NonFungibleGlobalId::new(council, NonFungibleLocalId::integer(2));
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(owner_account, owner_badge, dec!(1))
+        .call_method(
+            governance_component,
+            "make_proposal",
+            manifest_args!(0u64, Some(EligibilityRule::NonFungible(chair.clone()))),
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&owner_pk)],
+        )
+        .expect_commit_success();
+
+    let vote_with_council_nft = |ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>,
+                                 id: u64| {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungibles(
+                member_account,
+                council,
+                [NonFungibleLocalId::integer(id)],
+            )
+            .pop_from_auth_zone("eligibility_proof")
+            .call_method_with_name_lookup(governance_component, "vote_on_proposal", |lookup| {
+                (
+                    member_account,
+                    0u64,
+                    vec![ProposalVoteOptionId(0)],
+                    None::<VoteRationale>,
+                    Some(lookup.proof("eligibility_proof")),
+                )
+            })
+            .build();
+        ledger
+            .execute_manifest(
+                manifest,
+                vec![NonFungibleGlobalId::from_public_key(&member_pk)],
+            )
+            .is_commit_success()
+    };
+    assert!(!vote_with_council_nft(&mut ledger, 3));
+    assert!(vote_with_council_nft(&mut ledger, 2));
+}
+
+#[test]
+fn test_eligibility_fungible_resource() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (owner_badge, _owner_account, _owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let governance_component =
+        instantiate_governance(&mut ledger, owner_badge, create_governance_parameters());
+
+    // The holder keeps 990 tokens and sends 10 to a small holder
+    let (holder_pk, _holder_sk, holder_account) = ledger.new_allocated_account();
+    let token = ledger.create_fungible_resource(dec!(1000), 18, holder_account);
+    let (small_pk, _small_sk, small_account) = ledger.new_allocated_account();
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(holder_account, token, dec!(10))
+        .try_deposit_entire_worktop_or_abort(small_account, None)
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&holder_pk)],
+        )
+        .expect_commit_success();
+
+    let mut draft = create_temp_check_draft();
+    draft.eligibility = Some(EligibilityRule::Resource {
+        resource: token,
+        min_amount: dec!(100),
+    });
+    make_temperature_check(
+        &mut ledger,
+        governance_component,
+        holder_account,
+        &holder_pk,
+        draft,
+    );
+
+    let vote_with_tokens = |ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>,
+                            account: ComponentAddress,
+                            pk: &Secp256k1PublicKey,
+                            amount: Decimal| {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_amount(account, token, amount)
+            .pop_from_auth_zone("eligibility_proof")
+            .call_method_with_name_lookup(
+                governance_component,
+                "vote_on_temperature_check",
+                |lookup| {
+                    (
+                        account,
+                        0u64,
+                        TemperatureCheckVote::For,
+                        None::<VoteRationale>,
+                        Some(lookup.proof("eligibility_proof")),
+                    )
+                },
+            )
+            .build();
+        ledger.execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(pk)])
+    };
+
+    vote_with_tokens(&mut ledger, small_account, &small_pk, dec!(10)).expect_specific_failure(
+        |e| format!("{:?}", e).contains("Eligibility proof must show at least"),
+    );
+    vote_with_tokens(&mut ledger, holder_account, &holder_pk, dec!(990)).expect_commit_success();
+}
+
+#[test]
+fn test_vote_batch_rejects_gated_items() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (owner_badge, _owner_account, _owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let governance_component =
+        instantiate_governance(&mut ledger, owner_badge, create_governance_parameters());
+
+    let (member_pk, _member_sk, member_account) = ledger.new_allocated_account();
+    let council = ledger.create_non_fungible_resource(member_account);
+    let mut draft = create_temp_check_draft();
+    draft.eligibility = Some(EligibilityRule::Resource {
+        resource: council,
+        min_amount: dec!(1),
+    });
+    make_temperature_check(
+        &mut ledger,
+        governance_component,
+        member_account,
+        &member_pk,
+        draft,
+    );
+
+    let ballots: Vec<ManifestBallot> = vec![(
+        member_account,
+        0,
+        BallotVote::TemperatureCheck(TemperatureCheckVote::For),
+        None,
+    )];
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "vote_batch", manifest_args!(ballots))
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&member_pk)],
+        )
+        .expect_specific_failure(|e| {
+            format!("{:?}", e).contains("Gated items cannot be voted on in a batch")
+        });
+}